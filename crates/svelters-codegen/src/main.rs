@@ -11,6 +11,10 @@ use syn::Type;
 use ungrammar::{Grammar, Node, Rule, Token};
 use xshell::{cmd, Shell};
 
+/// Enums that are allowed a variant much larger than the others. Boxing the large variant would
+/// mean matching through the box everywhere the enum is used.
+const LARGE_VARIANT_ENUMS: &[&str] = &["MustacheItem"];
+
 fn main() -> anyhow::Result<()> {
     let grammar: Grammar = include_str!("../svelte.ungram").parse()?;
 
@@ -58,12 +62,16 @@ fn main() -> anyhow::Result<()> {
         };
 
         if is_enum {
+            let allow_large_variant = LARGE_VARIANT_ENUMS
+                .contains(&name.as_str())
+                .then(|| quote! { #[allow(clippy::large_enum_variant)] });
             let num_alts = fields.iter().filter(|f| f.is_alt).count();
             let variants = fields
                 .into_iter()
                 .map(|f| f.into_enum_variant(num_alts <= 1));
             if num_alts > 1 {
                 quote! {
+                    #allow_large_variant
                     #[derive(Debug, Spanned, EqIgnoreSpan, PartialEq, From, Serialize, Deserialize)]
                     #[serde(untagged)]
                     pub enum #name_ident {
@@ -72,6 +80,7 @@ fn main() -> anyhow::Result<()> {
                 }
             } else {
                 quote! {
+                    #allow_large_variant
                     #[derive(Debug, Spanned, EqIgnoreSpan, PartialEq, From)]
                     #[ast_serde]
                     pub enum #name_ident {
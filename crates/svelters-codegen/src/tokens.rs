@@ -4,24 +4,56 @@ pub const TOKEN_NAMES: &[(&str, &str)] = &[
     ("}", "MustacheClose"),
     ("<!--", "CommentStart"),
     ("-->", "CommentEnd"),
+    ("<", "TagOpen"),
+    ("</", "EndTagOpen"),
+    (">", "TagClose"),
+    ("/>", "SelfClosingTagClose"),
+    ("=", "Eq"),
+    ("quote", "Quote"),
+    (":", "Colon"),
+    ("|", "Pipe"),
+    ("...", "Spread"),
+    ("&", "Ampersand"),
+    ("on", "On"),
+    ("bind", "Bind"),
+    ("class", "Class"),
+    ("style", "Style"),
+    ("use", "Use"),
+    ("transition", "Transition"),
+    ("in", "In"),
+    ("out", "Out"),
+    ("animate", "Animate"),
+    ("let", "Let"),
     (",", "Comma"),
     ("(", "ParenOpen"),
     (")", "ParenClose"),
     ("@const", "ConstTag"),
     ("@debug", "DebugTag"),
     ("@html", "HtmlTag"),
+    ("@render", "RenderTag"),
     ("#key", "KeyOpen"),
     ("#if", "IfOpen"),
     ("#each", "EachOpen"),
+    ("#await", "AwaitOpen"),
+    ("#snippet", "SnippetOpen"),
+    (":else", "ElseBranch"),
+    (":then", "ThenBranch"),
+    (":catch", "CatchBranch"),
     ("/key", "KeyClose"),
     ("/if", "IfClose"),
     ("/each", "EachClose"),
     ("/await", "AwaitClose"),
+    ("/snippet", "SnippetClose"),
     ("as", "As"),
+    ("if", "If"),
+    ("then", "Then"),
+    ("catch", "Catch"),
 ];
 pub const TOKEN_TYPES: &[(&str, &str)] = &[
     ("expression", "Box<swc_ecma_ast::Expr>"),
     ("pattern", "swc_ecma_ast::Pat"),
     ("identifier", "swc_ecma_ast::Ident"),
     ("text", "String"),
+    ("node", "Node"),
+    ("module", "swc_ecma_ast::Module"),
 ];
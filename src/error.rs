@@ -1,6 +1,9 @@
+use crate::fix::Fix;
+use std::fmt;
 use swc_common::{Span, Spanned};
+pub use swc_ecma_parser::error::SyntaxError;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ParseErrorKind {
     MustacheNotClosed,
 
@@ -13,6 +16,9 @@ pub enum ParseErrorKind {
     /// Missing whitespace after `{@html`
     MissingWhitespaceAfterHtmlTag,
 
+    /// Missing whitespace after `{@render`
+    MissingWhitespaceAfterRenderTag,
+
     /// Missing whitespace after `{#block`
     MissingWhitespaceAfterBlockOpen,
 
@@ -22,41 +28,647 @@ pub enum ParseErrorKind {
     /// Missing whitespace after `as` in each open
     MissingWhitespaceAfterAs,
 
+    /// JavaScript in a mustache or `<script>` that swc couldn't parse, or only parsed by
+    /// recovering from an error
+    InvalidJavaScript(JavaScriptError),
+
+    /// swc panicked while parsing the JavaScript, so there's no error from it to report
+    JavaScriptParserFailed,
+
+    /// Each block without `as` between its expression and context
+    ExpectedEachAs,
+
+    /// Key of an each block without a closing `)`
+    EachKeyNotClosed,
+
     /// Expression given to `{@const ...}` was not a simple assignment expression
     InvalidConstArgs,
 
     /// One or more expressions given to `{@debug ...}` was not an identifier
     InvalidDebugArgs,
 
+    /// `{@const ...}` that isn't the immediate child of an if, each or snippet block, a then or
+    /// catch branch, a component or `<svelte:fragment>`
+    InvalidConstTagPlacement,
+
+    /// `{@debug ...}` in an attribute value, or inside `<textarea>` or `<title>`
+    InvalidDebugTagPlacement,
+
+    /// `{@html ...}` in an attribute value, or inside `<textarea>` or `<title>`
+    InvalidHtmlTagPlacement,
+
+    /// Expression given to `{@render ...}` was not a call, like `{@render children()}`
+    InvalidRenderExpression,
+
+    /// `{#snippet}` without a name
+    ExpectedSnippetName,
+
+    /// `{#snippet name}` without a parenthesised list of parameters, or with invalid ones
+    ExpectedSnippetParameters,
+
     /// Close block of an unknown type
     UnknownBlockClose,
 
+    /// Block continuation (like `{:then}`) of an unknown type
+    UnknownBlockContinuation,
+
+    /// `{:else}`, `{:then}` or `{:catch}` outside of a block
+    BlockContinuationOutsideBlock,
+
+    /// Block continuation that doesn't belong in the block it's in, like `{:then}` in an `{#if}`
+    /// or a second `{:else}`
+    InvalidBlockContinuation,
+
+    /// Block without a matching close, like `{#if}` without `{/if}`
+    BlockNotClosed,
+
+    /// Block close without a matching open block, like `{/if}` without `{#if}`
+    UnexpectedBlockClose,
+
+    /// `<svelte:component>` or `<svelte:element>` without a `this` attribute
+    MissingThisAttribute,
+
+    /// `<svelte:window>`, `<svelte:body>`, `<svelte:document>`, `<svelte:head>` or
+    /// `<svelte:options>` inside an element or block
+    InvalidSvelteMetaPlacement,
+
+    /// More than one of the same `<svelte:window>`, `<svelte:body>`, `<svelte:document>`,
+    /// `<svelte:head>` or `<svelte:options>` in a component
+    DuplicateSvelteMeta,
+
+    /// Children inside `<svelte:window>`, `<svelte:body>`, `<svelte:document>` or
+    /// `<svelte:options>`
+    InvalidSvelteMetaContent,
+
+    /// `<svelte:...>` tag that isn't one of svelte's special elements
+    UnknownSvelteTag,
+
+    /// Attribute on `<svelte:options>` that isn't a known option
+    UnknownSvelteOption,
+
+    /// Option in `<svelte:options>` with a value of the wrong type, or one that isn't static
+    InvalidSvelteOptionValue,
+
+    /// `<slot name="...">` with a name that isn't static, or is `default`
+    InvalidSlotName,
+
+    /// Directive other than `let:` on a `<slot>`
+    InvalidSlotElementAttribute,
+
+    /// `slot="..."` attribute with a value that isn't static
+    DynamicSlotAttribute,
+
+    /// `slot="..."` on an element that isn't a direct child of a component, or inside a custom
+    /// element
+    InvalidSlotAttributePlacement,
+
+    /// More than one child of a component passed to the same slot
+    DuplicateSlotAttribute,
+
+    /// `let:` directive on an element that doesn't receive slot props
+    InvalidLetDirectivePlacement,
+
     /// Unexpected block type
     UnexpectedBlockType,
 
     /// Expected a valid index identifier
     ExpectedEachIndex,
+
+    /// `<` was not followed by a valid tag name
+    ExpectedTagName,
+
+    /// Start or end tag was not closed with `>` or `/>`
+    TagNotClosed,
+
+    /// Element was never closed with a matching end tag
+    ElementNotClosed,
+
+    /// End tag that does not match any open element
+    UnexpectedEndTag,
+
+    /// End tag for an element that was already closed implicitly, like `</p>` after
+    /// `<p><div></div>`
+    AutoClosedElementEndTag,
+
+    /// End tag for a void element like `<br>`, which can't have children
+    InvalidVoidElementContent,
+
+    /// Expected the name of an attribute
+    ExpectedAttributeName,
+
+    /// Expected a value after `=` in an attribute
+    ExpectedAttributeValue,
+
+    /// Quoted attribute value is missing its closing quote, which is given
+    AttributeValueNotClosed(char),
+
+    /// Expression given to a shorthand attribute `{name}` was not an identifier
+    InvalidShorthandAttribute,
+
+    /// Attribute name has a `prefix:` that isn't a known directive
+    UnknownDirectiveType,
+
+    /// Missing name after the `:` of a directive
+    ExpectedDirectiveName,
+
+    /// Modifier that isn't allowed on this type of directive
+    IllegalDirectiveModifier,
+
+    /// Directive value was not a single expression
+    InvalidDirectiveValue,
+
+    /// Spread `{...expression}` used outside of a start tag
+    UnexpectedSpread,
+
+    /// Missing `...` in a spread attribute
+    ExpectedSpread,
+
+    /// `context` attribute of a `<script>` was something other than `"module"`
+    InvalidScriptContext,
+
+    /// More than one instance `<script>` in a component
+    DuplicateInstanceScript,
+
+    /// More than one `<script context="module">` in a component
+    DuplicateModuleScript,
+
+    /// More than one top level `<style>` in a component
+    DuplicateStyle,
+
+    /// Expected an identifier in CSS, like a class name or at-rule name
+    ExpectedCssIdentifier,
+
+    /// Expected a specific character in CSS, like the `{` starting a rule's block or a closing `]`
+    ExpectedCssToken(char),
+
+    /// A CSS selector that can't be parsed, like one ending with a combinator
+    InvalidCssSelector,
+
+    /// A CSS declaration without a value, like `color:;`
+    EmptyCssDeclaration,
+
+    /// Warning for `<script context="module">`, which svelte 5 replaces with `<script module>`
+    DeprecatedScriptContext,
+
+    /// Warning for a block with nothing but whitespace in it, like `{#if a} {/if}`
+    EmptyBlock,
+}
+
+impl ParseErrorKind {
+    /// Short text to show next to the span of the error, if there's something more specific to
+    /// say about it than the message.
+    pub fn label(&self) -> Option<&'static str> {
+        use ParseErrorKind::*;
+
+        Some(match self {
+            MustacheNotClosed => "expected `}`",
+            MissingWhitespaceAfterDebugTag
+            | MissingWhitespaceAfterConstTag
+            | MissingWhitespaceAfterHtmlTag
+            | MissingWhitespaceAfterRenderTag
+            | MissingWhitespaceAfterBlockOpen
+            | MissingWhitespaceAfterAs => "expected whitespace after this",
+            MissingWhitespaceBeforeAs => "expected whitespace before `as`",
+            ExpectedEachAs => "expected `as` here",
+            EachKeyNotClosed => "expected `)`",
+            ExpectedSpread => "expected `...`",
+            BlockNotClosed => "opened here",
+            ElementNotClosed => "opened here",
+            TagNotClosed => "expected `>`",
+            AttributeValueNotClosed(..) => "expected a closing quote",
+            DuplicateSvelteMeta
+            | DuplicateSlotAttribute
+            | DuplicateInstanceScript
+            | DuplicateModuleScript
+            | DuplicateStyle => "duplicate",
+            _ => return None,
+        })
+    }
+
+    /// A suggestion for how to fix the error, if there's a common fix.
+    pub fn help(&self) -> Option<&'static str> {
+        use ParseErrorKind::*;
+
+        Some(match self {
+            MustacheNotClosed => "add a `}` to close the mustache",
+            ExpectedEachAs => "each blocks look like `{#each items as item}`",
+            InvalidConstArgs => {
+                "`{@const}` takes an assignment, like `{@const area = width * height}`"
+            }
+            InvalidDebugArgs => "`{@debug}` takes a comma separated list of variable names",
+            InvalidConstTagPlacement => {
+                "`{@const}` must be the immediate child of a block, a component or \
+                 `<svelte:fragment>`"
+            }
+            InvalidRenderExpression => "call the snippet, like `{@render children()}`",
+            ExpectedSnippetParameters => "snippets look like `{#snippet name(params)}`",
+            BlockNotClosed => "add a matching close block, like `{/if}`",
+            MissingThisAttribute => "add a `this` attribute, like `this={component}`",
+            InvalidSlotName => "slot names must be static text, and can't be `default`",
+            AutoClosedElementEndTag => {
+                "the element was closed by the start tag before it, so this end tag can be \
+                 removed"
+            }
+            InvalidVoidElementContent => {
+                "void elements can't have end tags, so this can be removed"
+            }
+            InvalidShorthandAttribute => {
+                "use `name={expression}` for anything but a plain variable"
+            }
+            InvalidScriptContext => "the only valid context is `context=\"module\"`",
+            _ => return None,
+        })
+    }
+
+    /// Stable identifier for the kind of error. Where the svelte compiler has an equivalent error
+    /// this is the same as its code, so it can be used with `<!-- svelte-ignore -->`. As in svelte,
+    /// several kinds can share a code.
+    pub fn code(&self) -> &'static str {
+        self.code_and_template().0
+    }
+
+    /// Message for the error, with `%name%` placeholders for its [`params`](Self::params).
+    pub fn template(&self) -> &'static str {
+        self.code_and_template().1
+    }
+
+    /// Values of the placeholders in the error's [`template`](Self::template).
+    pub fn params(&self) -> Vec<(&'static str, String)> {
+        use ParseErrorKind::*;
+
+        let params: &[(&str, &str)] = match self {
+            InvalidJavaScript(error) => return vec![("message", error.message.clone())],
+            AttributeValueNotClosed(token) | ExpectedCssToken(token) => {
+                return vec![("token", token.to_string())]
+            }
+            MustacheNotClosed => &[("token", "}")],
+            ExpectedEachAs => &[("token", "as")],
+            ExpectedSpread => &[("token", "...")],
+            EachKeyNotClosed => &[("token", ")")],
+            ExpectedSnippetParameters => &[("token", "(")],
+            TagNotClosed => &[("token", ">")],
+            InvalidDebugTagPlacement => &[("name", "debug")],
+            InvalidHtmlTagPlacement => &[("name", "html")],
+            _ => &[],
+        };
+        params
+            .iter()
+            .map(|(name, value)| (*name, value.to_string()))
+            .collect()
+    }
+
+    fn code_and_template(&self) -> (&'static str, &'static str) {
+        use ParseErrorKind::*;
+
+        match self {
+            MustacheNotClosed
+            | ExpectedEachAs
+            | ExpectedSpread
+            | EachKeyNotClosed
+            | ExpectedSnippetParameters
+            | TagNotClosed
+            | AttributeValueNotClosed(..)
+            | ExpectedCssToken(..) => ("expected_token", "Expected token %token%"),
+            MissingWhitespaceAfterDebugTag
+            | MissingWhitespaceAfterConstTag
+            | MissingWhitespaceAfterHtmlTag
+            | MissingWhitespaceAfterRenderTag
+            | MissingWhitespaceAfterBlockOpen
+            | MissingWhitespaceBeforeAs
+            | MissingWhitespaceAfterAs => ("expected_whitespace", "Expected whitespace"),
+            InvalidJavaScript(..) => ("js_parse_error", "%message%"),
+            JavaScriptParserFailed => (
+                "js_parse_error",
+                "The JavaScript parser failed on this code",
+            ),
+            InvalidConstArgs => (
+                "const_tag_invalid_expression",
+                "{@const ...} must consist of a single variable declaration",
+            ),
+            InvalidDebugArgs => (
+                "debug_tag_invalid_arguments",
+                "{@debug ...} arguments must be identifiers, not arbitrary expressions",
+            ),
+            InvalidConstTagPlacement => (
+                "const_tag_invalid_placement",
+                "`{@const}` must be the immediate child of `{#snippet}`, `{#if}`, `{:else if}`, \
+                 `{:else}`, `{#each}`, `{:then}`, `{:catch}`, `<svelte:fragment>` or `<Component>`",
+            ),
+            InvalidDebugTagPlacement | InvalidHtmlTagPlacement => (
+                "tag_invalid_placement",
+                "{@%name% ...} tag cannot be in an attribute value, `<textarea>` or `<title>`",
+            ),
+            InvalidRenderExpression => (
+                "render_tag_invalid_expression",
+                "`{@render ...}` tags can only contain call expressions",
+            ),
+            ExpectedSnippetName | ExpectedEachIndex => {
+                ("expected_identifier", "Expected an identifier")
+            }
+            UnknownBlockClose | UnexpectedBlockClose => {
+                ("block_unexpected_close", "Unexpected block closing tag")
+            }
+            UnknownBlockContinuation | BlockContinuationOutsideBlock | InvalidBlockContinuation => (
+                "block_invalid_continuation_placement",
+                "{:...} block is invalid at this position (did you forget to close the preceding \
+                 element or block?)",
+            ),
+            BlockNotClosed => ("block_unclosed", "Block was left open"),
+            UnexpectedBlockType => (
+                "expected_block_type",
+                "Expected 'if', 'each', 'await', 'key' or 'snippet'",
+            ),
+            MissingThisAttribute => (
+                "svelte_component_missing_this",
+                "`<svelte:component>` and `<svelte:element>` must have a 'this' attribute",
+            ),
+            InvalidSvelteMetaPlacement => (
+                "svelte_meta_invalid_placement",
+                "`<svelte:...>` tags like this cannot be inside elements or blocks",
+            ),
+            DuplicateSvelteMeta => (
+                "svelte_meta_duplicate",
+                "A component can only have one of each `<svelte:...>` element",
+            ),
+            InvalidSvelteMetaContent => (
+                "svelte_meta_invalid_content",
+                "`<svelte:...>` elements like this cannot have children",
+            ),
+            UnknownSvelteTag => (
+                "svelte_meta_invalid_tag",
+                "Valid `<svelte:...>` tag names are svelte:head, svelte:options, svelte:window, \
+                 svelte:document, svelte:body, svelte:element, svelte:component, svelte:self or \
+                 svelte:fragment",
+            ),
+            UnknownSvelteOption => (
+                "svelte_options_unknown_attribute",
+                "`<svelte:options>` unknown attribute",
+            ),
+            InvalidSvelteOptionValue => (
+                "svelte_options_invalid_attribute_value",
+                "`<svelte:options>` attribute has an invalid value",
+            ),
+            InvalidSlotName => (
+                "slot_element_invalid_name",
+                "slot attribute must be a static value, and can't be `default`",
+            ),
+            InvalidSlotElementAttribute => (
+                "slot_element_invalid_attribute",
+                "`<slot>` can only receive attributes and (optionally) let directives",
+            ),
+            DynamicSlotAttribute => (
+                "slot_attribute_invalid",
+                "slot attribute must be a static value",
+            ),
+            InvalidSlotAttributePlacement => (
+                "slot_attribute_invalid_placement",
+                "Element with a slot='...' attribute must be a child of a component or a \
+                 descendant of a custom element",
+            ),
+            DuplicateSlotAttribute => (
+                "slot_attribute_duplicate",
+                "Duplicate slot name in component",
+            ),
+            InvalidLetDirectivePlacement => (
+                "let_directive_invalid_placement",
+                "`let:` directive at invalid position",
+            ),
+            ExpectedTagName => (
+                "tag_invalid_name",
+                "Expected a valid element or component name",
+            ),
+            ElementNotClosed => ("element_unclosed", "Element was left open"),
+            UnexpectedEndTag => (
+                "element_invalid_closing_tag",
+                "Closing tag attempted to close an element that was not open",
+            ),
+            AutoClosedElementEndTag => (
+                "element_invalid_closing_tag_autoclosed",
+                "Closing tag attempted to close element that was already automatically closed",
+            ),
+            InvalidVoidElementContent => (
+                "void_element_invalid_content",
+                "Void elements cannot have children or closing tags",
+            ),
+            ExpectedAttributeName => ("attribute_invalid_name", "Expected an attribute name"),
+            ExpectedAttributeValue => ("expected_attribute_value", "Expected attribute value"),
+            InvalidShorthandAttribute => (
+                "attribute_invalid_shorthand",
+                "Attribute shorthand must be an identifier",
+            ),
+            UnknownDirectiveType => ("directive_invalid_type", "Unknown directive type"),
+            ExpectedDirectiveName => ("directive_missing_name", "Directive name cannot be empty"),
+            IllegalDirectiveModifier => (
+                "directive_invalid_modifier",
+                "Modifier is not valid on this directive",
+            ),
+            InvalidDirectiveValue => (
+                "directive_invalid_value",
+                "Directive value must be a JavaScript expression enclosed in curly braces",
+            ),
+            UnexpectedSpread => (
+                "spread_invalid_placement",
+                "Spread is only allowed on elements and components",
+            ),
+            InvalidScriptContext => (
+                "script_invalid_context",
+                "If the context attribute is supplied, its value must be \"module\"",
+            ),
+            DuplicateInstanceScript | DuplicateModuleScript => (
+                "script_duplicate",
+                "A component can have a single top-level `<script>` element and/or a single \
+                 top-level `<script context=\"module\">` element",
+            ),
+            DuplicateStyle => (
+                "style_duplicate",
+                "A component can have a single top-level `<style>` element",
+            ),
+            ExpectedCssIdentifier => ("css_expected_identifier", "Expected a valid CSS identifier"),
+            InvalidCssSelector => ("css_selector_invalid", "Invalid selector"),
+            EmptyCssDeclaration => ("css_empty_declaration", "Declaration cannot be empty"),
+            DeprecatedScriptContext => (
+                "script_context_deprecated",
+                "`context=\"module\"` is deprecated, use the `module` attribute instead",
+            ),
+            EmptyBlock => ("block_empty", "Empty block"),
+        }
+    }
 }
 
-#[derive(Debug, Clone, Copy, Spanned, PartialEq)]
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut message = self.template().to_string();
+        for (name, value) in self.params() {
+            message = message.replace(&format!("%{name}%"), &value);
+        }
+        f.write_str(&message)
+    }
+}
+
+/// Syntax error reported by swc while parsing JavaScript
+#[derive(Debug, Clone, PartialEq)]
+pub struct JavaScriptError {
+    pub kind: SyntaxError,
+    pub message: String,
+}
+
+impl From<SyntaxError> for JavaScriptError {
+    fn from(kind: SyntaxError) -> Self {
+        Self {
+            message: kind.msg().into_owned(),
+            kind,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Spanned, PartialEq)]
 pub struct ParseError {
     pub kind: ParseErrorKind,
     pub span: Span,
+    /// Suggested fixes, with the most likely first.
+    pub fixes: Vec<Fix>,
 }
 
 impl ParseError {
     pub fn new(kind: ParseErrorKind, span: Span) -> Self {
-        Self { kind, span }
+        Self {
+            kind,
+            span,
+            fixes: Vec::new(),
+        }
+    }
+
+    pub fn with_fix(mut self, fix: Fix) -> Self {
+        self.fixes.push(fix);
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}..{}", self.kind, self.span.lo.0, self.span.hi.0)
+    }
+}
+
+impl From<swc_ecma_parser::error::Error> for ParseError {
+    fn from(error: swc_ecma_parser::error::Error) -> Self {
+        let span = error.span();
+        Self::new(
+            ParseErrorKind::InvalidJavaScript(error.into_kind().into()),
+            span,
+        )
+    }
+}
+
+/// How serious a diagnostic is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// Syntax that is invalid, and wouldn't be compiled by svelte
+    Error,
+    /// Syntax that is valid but probably not what was meant, or is deprecated
+    Warning,
+    /// Suggestion that isn't a problem on its own
+    Hint,
+}
+
+/// Extra information about a diagnostic that editors can use to display it differently.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticTag {
+    /// Code that can be removed without changing anything, usually shown faded out
+    Unnecessary,
+    /// Syntax that still works but has been deprecated, usually shown struck through
+    Deprecated,
+}
+
+/// Another span in the source that helps explain a diagnostic, like where a block was opened.
+#[derive(Debug, Clone, Spanned, PartialEq)]
+pub struct RelatedSpan {
+    pub span: Span,
+    pub message: String,
+}
+
+/// An error, warning or hint about the source.
+#[derive(Debug, Clone, Spanned, PartialEq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub kind: ParseErrorKind,
+    pub span: Span,
+    pub related: Vec<RelatedSpan>,
+    pub tags: Vec<DiagnosticTag>,
+    /// Suggested fixes, with the most likely first.
+    pub fixes: Vec<Fix>,
+}
+
+impl Diagnostic {
+    pub fn new(severity: Severity, kind: ParseErrorKind, span: Span) -> Self {
+        Self {
+            severity,
+            kind,
+            span,
+            related: Vec::new(),
+            tags: Vec::new(),
+            fixes: Vec::new(),
+        }
+    }
+
+    pub fn warning(kind: ParseErrorKind, span: Span) -> Self {
+        Self::new(Severity::Warning, kind, span)
+    }
+
+    pub fn with_related(mut self, span: Span, message: impl Into<String>) -> Self {
+        self.related.push(RelatedSpan {
+            span,
+            message: message.into(),
+        });
+        self
+    }
+
+    pub fn with_tag(mut self, tag: DiagnosticTag) -> Self {
+        self.tags.push(tag);
+        self
+    }
+
+    pub fn with_fix(mut self, fix: Fix) -> Self {
+        self.fixes.push(fix);
+        self
+    }
+}
+
+impl From<ParseError> for Diagnostic {
+    fn from(error: ParseError) -> Self {
+        Self {
+            fixes: error.fixes,
+            ..Self::new(Severity::Error, error.kind, error.span)
+        }
+    }
+}
+
+impl From<Diagnostic> for ParseError {
+    fn from(diagnostic: Diagnostic) -> Self {
+        Self {
+            kind: diagnostic.kind,
+            span: diagnostic.span,
+            fixes: diagnostic.fixes,
+        }
     }
 }
 
 pub trait ErrorReporter {
     fn report_parse_error(&mut self, error: ParseError);
+
+    /// Reports a diagnostic of any severity. Unless this is implemented, errors are passed on to
+    /// [`report_parse_error`](Self::report_parse_error) and anything less severe is ignored.
+    fn report_diagnostic(&mut self, diagnostic: Diagnostic) {
+        if diagnostic.severity == Severity::Error {
+            self.report_parse_error(diagnostic.into());
+        }
+    }
 }
 
 #[derive(Default)]
 pub struct CollectingErrorReporter {
     parse_errors: Vec<ParseError>,
+    diagnostics: Vec<Diagnostic>,
 }
 
 impl CollectingErrorReporter {
@@ -64,6 +676,7 @@ impl CollectingErrorReporter {
         Self::default()
     }
 
+    /// Whether no errors have been reported. Warnings and hints aren't counted.
     pub fn is_empty(&self) -> bool {
         self.parse_errors.is_empty()
     }
@@ -71,10 +684,41 @@ impl CollectingErrorReporter {
     pub fn parse_errors(&self) -> &[ParseError] {
         self.parse_errors.as_ref()
     }
+
+    /// All of the diagnostics that have been reported, including errors, in the order they were
+    /// reported.
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        self.diagnostics.as_ref()
+    }
+
+    pub fn diagnostics_with_severity(
+        &self,
+        severity: Severity,
+    ) -> impl Iterator<Item = &Diagnostic> {
+        self.diagnostics
+            .iter()
+            .filter(move |diagnostic| diagnostic.severity == severity)
+    }
+
+    pub fn warnings(&self) -> impl Iterator<Item = &Diagnostic> {
+        self.diagnostics_with_severity(Severity::Warning)
+    }
+
+    pub fn hints(&self) -> impl Iterator<Item = &Diagnostic> {
+        self.diagnostics_with_severity(Severity::Hint)
+    }
 }
 
 impl ErrorReporter for CollectingErrorReporter {
     fn report_parse_error(&mut self, error: ParseError) {
+        self.diagnostics.push(error.clone().into());
         self.parse_errors.push(error);
     }
+
+    fn report_diagnostic(&mut self, diagnostic: Diagnostic) {
+        if diagnostic.severity == Severity::Error {
+            self.parse_errors.push(diagnostic.clone().into());
+        }
+        self.diagnostics.push(diagnostic);
+    }
 }
@@ -0,0 +1,101 @@
+//! Fixes that can be applied to the source automatically, like inserting a missing `}`.
+
+use crate::{error::ParseError, parser::new_span};
+use swc_common::Span;
+
+/// Replaces the text in `span` with `new_text`. An empty span inserts the text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    pub span: Span,
+    pub new_text: String,
+}
+
+impl TextEdit {
+    pub fn insert(position: usize, text: impl Into<String>) -> Self {
+        Self::replace(new_span(position, position), text)
+    }
+
+    pub fn replace(span: Span, text: impl Into<String>) -> Self {
+        Self {
+            span,
+            new_text: text.into(),
+        }
+    }
+
+    pub fn delete(span: Span) -> Self {
+        Self::replace(span, "")
+    }
+}
+
+/// A suggested fix for an error, made up of edits that should be applied together.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fix {
+    /// Short description of the fix, for showing as a code action
+    pub title: String,
+    pub edits: Vec<TextEdit>,
+}
+
+impl Fix {
+    pub fn new(title: impl Into<String>, edits: Vec<TextEdit>) -> Self {
+        Self {
+            title: title.into(),
+            edits,
+        }
+    }
+
+    pub fn insert(title: impl Into<String>, position: usize, text: impl Into<String>) -> Self {
+        Self::new(title, vec![TextEdit::insert(position, text)])
+    }
+
+    pub fn replace(title: impl Into<String>, span: Span, text: impl Into<String>) -> Self {
+        Self::new(title, vec![TextEdit::replace(span, text)])
+    }
+
+    pub fn delete(title: impl Into<String>, span: Span) -> Self {
+        Self::new(title, vec![TextEdit::delete(span)])
+    }
+}
+
+/// Applies the first fix of each error to `source`. Fixes that overlap one that has already been
+/// applied, or that don't fit in the source, are skipped.
+pub fn apply_fixes(source: &str, errors: &[ParseError]) -> String {
+    let mut fixes: Vec<Vec<&TextEdit>> = errors
+        .iter()
+        .filter_map(|error| error.fixes.first())
+        .map(|fix| {
+            let mut edits: Vec<_> = fix.edits.iter().collect();
+            edits.sort_by_key(|edit| (edit.span.lo, edit.span.hi));
+            edits
+        })
+        .filter(|edits| !edits.is_empty())
+        .collect();
+    // Stable, so fixes inserting at the same position are applied in the order of their errors
+    fixes.sort_by_key(|edits| edits[0].span.lo);
+
+    let mut edits = Vec::new();
+    let mut applied_to = 0;
+    for fix in fixes {
+        let mut end = applied_to;
+        let fits = fix.iter().all(|edit| {
+            let (lo, hi) = (edit.span.lo.0 as usize, edit.span.hi.0 as usize);
+            let fits =
+                lo >= end && lo <= hi && source.is_char_boundary(lo) && source.is_char_boundary(hi);
+            end = hi;
+            fits
+        });
+        if fits {
+            applied_to = end;
+            edits.extend(fix);
+        }
+    }
+
+    let mut fixed = String::with_capacity(source.len());
+    let mut position = 0;
+    for edit in edits {
+        fixed.push_str(&source[position..edit.span.lo.0 as usize]);
+        fixed.push_str(&edit.new_text);
+        position = edit.span.hi.0 as usize;
+    }
+    fixed.push_str(&source[position..]);
+    fixed
+}
@@ -13,6 +13,100 @@ pub enum Node {
     Comment(Comment),
     #[tag("CommentText")]
     CommentText(CommentText),
+    #[tag("Element")]
+    Element(Element),
+    #[tag("StartTag")]
+    StartTag(StartTag),
+    #[tag("EndTag")]
+    EndTag(EndTag),
+    #[tag("SvelteSelf")]
+    SvelteSelf(SvelteSelf),
+    #[tag("SvelteComponent")]
+    SvelteComponent(SvelteComponent),
+    #[tag("SvelteElement")]
+    SvelteElement(SvelteElement),
+    #[tag("SvelteWindow")]
+    SvelteWindow(SvelteWindow),
+    #[tag("SvelteBody")]
+    SvelteBody(SvelteBody),
+    #[tag("SvelteDocument")]
+    SvelteDocument(SvelteDocument),
+    #[tag("SvelteHead")]
+    SvelteHead(SvelteHead),
+    #[tag("SvelteFragment")]
+    SvelteFragment(SvelteFragment),
+    #[tag("Slot")]
+    Slot(Slot),
+    #[tag("SvelteOptionsElement")]
+    SvelteOptionsElement(SvelteOptionsElement),
+    #[tag("TagName")]
+    TagName(TagName),
+    #[tag("Attribute")]
+    Attribute(Attribute),
+    #[tag("HtmlAttribute")]
+    HtmlAttribute(HtmlAttribute),
+    #[tag("Directive")]
+    Directive(Directive),
+    #[tag("ShorthandAttribute")]
+    ShorthandAttribute(ShorthandAttribute),
+    #[tag("SpreadAttribute")]
+    SpreadAttribute(SpreadAttribute),
+    #[tag("AttributeName")]
+    AttributeName(AttributeName),
+    #[tag("AttributeInitializer")]
+    AttributeInitializer(AttributeInitializer),
+    #[tag("AttributeValue")]
+    AttributeValue(AttributeValue),
+    #[tag("AttributeMustache")]
+    AttributeMustache(AttributeMustache),
+    #[tag("DirectiveName")]
+    DirectiveName(DirectiveName),
+    #[tag("DirectiveModifier")]
+    DirectiveModifier(DirectiveModifier),
+    #[tag("InstanceScript")]
+    InstanceScript(InstanceScript),
+    #[tag("ModuleScript")]
+    ModuleScript(ModuleScript),
+    #[tag("Style")]
+    Style(Style),
+    #[tag("StyleSheet")]
+    StyleSheet(StyleSheet),
+    #[tag("CssStyleRule")]
+    CssStyleRule(CssStyleRule),
+    #[tag("CssAtRule")]
+    CssAtRule(CssAtRule),
+    #[tag("CssSelectorList")]
+    CssSelectorList(CssSelectorList),
+    #[tag("CssBlock")]
+    CssBlock(CssBlock),
+    #[tag("CssDeclaration")]
+    CssDeclaration(CssDeclaration),
+    #[tag("CssComplexSelector")]
+    CssComplexSelector(CssComplexSelector),
+    #[tag("CssRelativeSelector")]
+    CssRelativeSelector(CssRelativeSelector),
+    #[tag("CssCombinator")]
+    CssCombinator(CssCombinator),
+    #[tag("CssTypeSelector")]
+    CssTypeSelector(CssTypeSelector),
+    #[tag("CssIdSelector")]
+    CssIdSelector(CssIdSelector),
+    #[tag("CssClassSelector")]
+    CssClassSelector(CssClassSelector),
+    #[tag("CssAttributeSelector")]
+    CssAttributeSelector(CssAttributeSelector),
+    #[tag("CssPseudoElementSelector")]
+    CssPseudoElementSelector(CssPseudoElementSelector),
+    #[tag("CssPseudoClassSelector")]
+    CssPseudoClassSelector(CssPseudoClassSelector),
+    #[tag("CssGlobalSelector")]
+    CssGlobalSelector(CssGlobalSelector),
+    #[tag("CssNestingSelector")]
+    CssNestingSelector(CssNestingSelector),
+    #[tag("CssPercentage")]
+    CssPercentage(CssPercentage),
+    #[tag("CssNth")]
+    CssNth(CssNth),
     #[tag("Mustache")]
     Mustache(Mustache),
     #[tag("RawMustacheTag")]
@@ -21,23 +115,54 @@ pub enum Node {
     DebugTag(DebugTag),
     #[tag("ConstTag")]
     ConstTag(ConstTag),
+    #[tag("RenderTag")]
+    RenderTag(RenderTag),
+    #[tag("IfBlock")]
+    IfBlock(IfBlock),
+    #[tag("BlockBranch")]
+    BlockBranch(BlockBranch),
+    #[tag("EachBlock")]
+    EachBlock(EachBlock),
+    #[tag("AwaitBlock")]
+    AwaitBlock(AwaitBlock),
+    #[tag("KeyBlock")]
+    KeyBlock(KeyBlock),
+    #[tag("SnippetBlock")]
+    SnippetBlock(SnippetBlock),
     #[tag("IfBlockOpen")]
     IfBlockOpen(IfBlockOpen),
     #[tag("EachBlockOpen")]
     EachBlockOpen(EachBlockOpen),
+    #[tag("AwaitBlockOpen")]
+    AwaitBlockOpen(AwaitBlockOpen),
     #[tag("KeyBlockOpen")]
     KeyBlockOpen(KeyBlockOpen),
+    #[tag("SnippetBlockOpen")]
+    SnippetBlockOpen(SnippetBlockOpen),
     #[tag("EachAs")]
     EachAs(EachAs),
     #[tag("EachIndex")]
     EachIndex(EachIndex),
     #[tag("EachKey")]
     EachKey(EachKey),
+    #[tag("AwaitThen")]
+    AwaitThen(AwaitThen),
+    #[tag("AwaitCatch")]
+    AwaitCatch(AwaitCatch),
+    #[tag("ElseBlock")]
+    ElseBlock(ElseBlock),
+    #[tag("ElseIfBlock")]
+    ElseIfBlock(ElseIfBlock),
+    #[tag("ThenBlock")]
+    ThenBlock(ThenBlock),
+    #[tag("CatchBlock")]
+    CatchBlock(CatchBlock),
 }
 #[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
 #[ast_serde("Text")]
 pub struct Text {
     pub text: String,
+    pub data: String,
     pub span: Span,
 }
 #[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
@@ -61,6 +186,449 @@ pub struct CommentText {
     pub span: Span,
 }
 #[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("Element")]
+pub struct Element {
+    pub start_tag: StartTag,
+    pub children: Vec<Node>,
+    pub end_tag: Option<EndTag>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("StartTag")]
+pub struct StartTag {
+    pub tag_open: TagOpenToken,
+    pub tag_name: TagName,
+    pub attributes: Vec<Attribute>,
+    pub trailing_whitespace: Option<WhitespaceToken>,
+    pub start_tag_close: Option<StartTagClose>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("EndTag")]
+pub struct EndTag {
+    pub end_tag_open: EndTagOpenToken,
+    pub tag_name: TagName,
+    pub whitespace: Option<WhitespaceToken>,
+    pub tag_close: Option<TagCloseToken>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("SvelteSelf")]
+pub struct SvelteSelf {
+    pub start_tag: StartTag,
+    pub children: Vec<Node>,
+    pub end_tag: Option<EndTag>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("SvelteComponent")]
+pub struct SvelteComponent {
+    pub start_tag: StartTag,
+    pub children: Vec<Node>,
+    pub end_tag: Option<EndTag>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("SvelteElement")]
+pub struct SvelteElement {
+    pub start_tag: StartTag,
+    pub children: Vec<Node>,
+    pub end_tag: Option<EndTag>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("SvelteWindow")]
+pub struct SvelteWindow {
+    pub start_tag: StartTag,
+    pub children: Vec<Node>,
+    pub end_tag: Option<EndTag>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("SvelteBody")]
+pub struct SvelteBody {
+    pub start_tag: StartTag,
+    pub children: Vec<Node>,
+    pub end_tag: Option<EndTag>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("SvelteDocument")]
+pub struct SvelteDocument {
+    pub start_tag: StartTag,
+    pub children: Vec<Node>,
+    pub end_tag: Option<EndTag>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("SvelteHead")]
+pub struct SvelteHead {
+    pub start_tag: StartTag,
+    pub children: Vec<Node>,
+    pub end_tag: Option<EndTag>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("SvelteFragment")]
+pub struct SvelteFragment {
+    pub start_tag: StartTag,
+    pub children: Vec<Node>,
+    pub end_tag: Option<EndTag>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("Slot")]
+pub struct Slot {
+    pub start_tag: StartTag,
+    pub children: Vec<Node>,
+    pub end_tag: Option<EndTag>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("SvelteOptionsElement")]
+pub struct SvelteOptionsElement {
+    pub start_tag: StartTag,
+    pub children: Vec<Node>,
+    pub end_tag: Option<EndTag>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("TagName")]
+pub struct TagName {
+    pub text: String,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("Attribute")]
+pub struct Attribute {
+    pub whitespace: Option<WhitespaceToken>,
+    pub attribute_item: AttributeItem,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq, From)]
+#[ast_serde]
+pub enum StartTagClose {
+    #[tag("TagClose")]
+    TagClose(TagCloseToken),
+    #[tag("SelfClosingTagClose")]
+    SelfClosingTagClose(SelfClosingTagCloseToken),
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq, From)]
+#[ast_serde]
+pub enum AttributeItem {
+    #[tag("HtmlAttribute")]
+    HtmlAttribute(HtmlAttribute),
+    #[tag("Directive")]
+    Directive(Directive),
+    #[tag("ShorthandAttribute")]
+    ShorthandAttribute(ShorthandAttribute),
+    #[tag("SpreadAttribute")]
+    SpreadAttribute(SpreadAttribute),
+    #[tag("Unknown")]
+    Unknown(InvalidSyntax),
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("HtmlAttribute")]
+pub struct HtmlAttribute {
+    pub attribute_name: AttributeName,
+    pub attribute_initializer: Option<AttributeInitializer>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("Directive")]
+pub struct Directive {
+    pub directive_kind: DirectiveKind,
+    pub colon: ColonToken,
+    pub directive_name: DirectiveName,
+    pub modifiers: Vec<DirectiveModifier>,
+    pub attribute_initializer: Option<AttributeInitializer>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("ShorthandAttribute")]
+pub struct ShorthandAttribute {
+    pub attribute_mustache: AttributeMustache,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("SpreadAttribute")]
+pub struct SpreadAttribute {
+    pub mustache_open: MustacheOpenToken,
+    pub leading_whitespace: Option<WhitespaceToken>,
+    pub spread: SpreadToken,
+    pub expression: Box<swc_ecma_ast::Expr>,
+    pub trailing_whitespace: Option<WhitespaceToken>,
+    pub mustache_close: Option<MustacheCloseToken>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("AttributeName")]
+pub struct AttributeName {
+    pub text: String,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("AttributeInitializer")]
+pub struct AttributeInitializer {
+    pub leading_ws: Option<WhitespaceToken>,
+    pub eq: EqToken,
+    pub trailing_ws: Option<WhitespaceToken>,
+    pub attribute_value: AttributeValue,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("AttributeValue")]
+pub struct AttributeValue {
+    pub open_quote: Option<QuoteToken>,
+    pub parts: Vec<AttributeValuePart>,
+    pub close_quote: Option<QuoteToken>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq, From)]
+#[ast_serde]
+pub enum AttributeValuePart {
+    #[tag("Text")]
+    Text(Text),
+    #[tag("AttributeMustache")]
+    AttributeMustache(AttributeMustache),
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("AttributeMustache")]
+pub struct AttributeMustache {
+    pub mustache_open: MustacheOpenToken,
+    pub leading_whitespace: Option<WhitespaceToken>,
+    pub expression: Box<swc_ecma_ast::Expr>,
+    pub trailing_whitespace: Option<WhitespaceToken>,
+    pub mustache_close: Option<MustacheCloseToken>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq, From)]
+#[ast_serde]
+pub enum DirectiveKind {
+    #[tag("On")]
+    On(OnToken),
+    #[tag("Bind")]
+    Bind(BindToken),
+    #[tag("Class")]
+    Class(ClassToken),
+    #[tag("Style")]
+    Style(StyleToken),
+    #[tag("Use")]
+    Use(UseToken),
+    #[tag("Transition")]
+    Transition(TransitionToken),
+    #[tag("In")]
+    In(InToken),
+    #[tag("Out")]
+    Out(OutToken),
+    #[tag("Animate")]
+    Animate(AnimateToken),
+    #[tag("Let")]
+    Let(LetToken),
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("DirectiveName")]
+pub struct DirectiveName {
+    pub text: String,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("DirectiveModifier")]
+pub struct DirectiveModifier {
+    pub pipe: PipeToken,
+    pub text: String,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("InstanceScript")]
+pub struct InstanceScript {
+    pub start_tag: StartTag,
+    pub module: swc_ecma_ast::Module,
+    pub end_tag: Option<EndTag>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("ModuleScript")]
+pub struct ModuleScript {
+    pub start_tag: StartTag,
+    pub module: swc_ecma_ast::Module,
+    pub end_tag: Option<EndTag>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("Style")]
+pub struct Style {
+    pub start_tag: StartTag,
+    pub style_sheet: StyleSheet,
+    pub end_tag: Option<EndTag>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("StyleSheet")]
+pub struct StyleSheet {
+    pub rules: Vec<CssRule>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq, From)]
+#[ast_serde]
+pub enum CssRule {
+    #[tag("CssStyleRule")]
+    CssStyleRule(CssStyleRule),
+    #[tag("CssAtRule")]
+    CssAtRule(CssAtRule),
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("CssStyleRule")]
+pub struct CssStyleRule {
+    pub css_selector_list: CssSelectorList,
+    pub css_block: CssBlock,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("CssAtRule")]
+pub struct CssAtRule {
+    pub name: String,
+    pub prelude: String,
+    pub css_block: Option<CssBlock>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("CssSelectorList")]
+pub struct CssSelectorList {
+    pub selectors: Vec<CssComplexSelector>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("CssBlock")]
+pub struct CssBlock {
+    pub children: Vec<CssBlockItem>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq, From)]
+#[ast_serde]
+pub enum CssBlockItem {
+    #[tag("CssDeclaration")]
+    CssDeclaration(CssDeclaration),
+    #[tag("CssStyleRule")]
+    CssStyleRule(CssStyleRule),
+    #[tag("CssAtRule")]
+    CssAtRule(CssAtRule),
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("CssDeclaration")]
+pub struct CssDeclaration {
+    pub property: String,
+    pub value: String,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("CssComplexSelector")]
+pub struct CssComplexSelector {
+    pub relative_selectors: Vec<CssRelativeSelector>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("CssRelativeSelector")]
+pub struct CssRelativeSelector {
+    pub css_combinator: Option<CssCombinator>,
+    pub selectors: Vec<CssSimpleSelector>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("CssCombinator")]
+pub struct CssCombinator {
+    pub text: String,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq, From)]
+#[ast_serde]
+pub enum CssSimpleSelector {
+    #[tag("CssTypeSelector")]
+    CssTypeSelector(CssTypeSelector),
+    #[tag("CssIdSelector")]
+    CssIdSelector(CssIdSelector),
+    #[tag("CssClassSelector")]
+    CssClassSelector(CssClassSelector),
+    #[tag("CssAttributeSelector")]
+    CssAttributeSelector(CssAttributeSelector),
+    #[tag("CssPseudoElementSelector")]
+    CssPseudoElementSelector(CssPseudoElementSelector),
+    #[tag("CssPseudoClassSelector")]
+    CssPseudoClassSelector(CssPseudoClassSelector),
+    #[tag("CssGlobalSelector")]
+    CssGlobalSelector(CssGlobalSelector),
+    #[tag("CssNestingSelector")]
+    CssNestingSelector(CssNestingSelector),
+    #[tag("CssPercentage")]
+    CssPercentage(CssPercentage),
+    #[tag("CssNth")]
+    CssNth(CssNth),
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("CssTypeSelector")]
+pub struct CssTypeSelector {
+    pub name: String,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("CssIdSelector")]
+pub struct CssIdSelector {
+    pub name: String,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("CssClassSelector")]
+pub struct CssClassSelector {
+    pub name: String,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("CssAttributeSelector")]
+pub struct CssAttributeSelector {
+    pub name: String,
+    pub matcher: Option<String>,
+    pub value: Option<String>,
+    pub flags: Option<String>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("CssPseudoElementSelector")]
+pub struct CssPseudoElementSelector {
+    pub name: String,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("CssPseudoClassSelector")]
+pub struct CssPseudoClassSelector {
+    pub name: String,
+    pub args: Option<CssSelectorList>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("CssGlobalSelector")]
+pub struct CssGlobalSelector {
+    pub args: Option<CssSelectorList>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("CssNestingSelector")]
+pub struct CssNestingSelector {
+    pub ampersand: AmpersandToken,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("CssPercentage")]
+pub struct CssPercentage {
+    pub value: String,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("CssNth")]
+pub struct CssNth {
+    pub value: String,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
 #[ast_serde("Mustache")]
 pub struct Mustache {
     pub mustache_open: MustacheOpenToken,
@@ -70,14 +638,17 @@ pub struct Mustache {
     pub mustache_close: Option<MustacheCloseToken>,
     pub span: Span,
 }
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Spanned, EqIgnoreSpan, PartialEq, From, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum MustacheItem {
     BlockOpen(BlockOpen),
+    BlockContinuation(BlockContinuation),
     BlockClose(BlockClose),
     RawMustacheTag(RawMustacheTag),
     DebugTag(DebugTag),
     ConstTag(ConstTag),
+    RenderTag(RenderTag),
     Expression(Box<swc_ecma_ast::Expr>),
     InvalidSyntax(InvalidSyntax),
 }
@@ -88,8 +659,26 @@ pub enum BlockOpen {
     IfBlockOpen(IfBlockOpen),
     #[tag("EachBlockOpen")]
     EachBlockOpen(EachBlockOpen),
+    #[tag("AwaitBlockOpen")]
+    AwaitBlockOpen(AwaitBlockOpen),
     #[tag("KeyBlockOpen")]
     KeyBlockOpen(KeyBlockOpen),
+    #[tag("SnippetBlockOpen")]
+    SnippetBlockOpen(SnippetBlockOpen),
+    #[tag("Unknown")]
+    Unknown(InvalidSyntax),
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq, From)]
+#[ast_serde]
+pub enum BlockContinuation {
+    #[tag("ElseBlock")]
+    ElseBlock(ElseBlock),
+    #[tag("ElseIfBlock")]
+    ElseIfBlock(ElseIfBlock),
+    #[tag("ThenBlock")]
+    ThenBlock(ThenBlock),
+    #[tag("CatchBlock")]
+    CatchBlock(CatchBlock),
     #[tag("Unknown")]
     Unknown(InvalidSyntax),
 }
@@ -104,6 +693,8 @@ pub enum BlockClose {
     AwaitClose(AwaitCloseToken),
     #[tag("KeyClose")]
     KeyClose(KeyCloseToken),
+    #[tag("SnippetClose")]
+    SnippetClose(SnippetCloseToken),
     #[tag("Unknown")]
     Unknown(InvalidSyntax),
 }
@@ -132,6 +723,64 @@ pub struct ConstTag {
     pub span: Span,
 }
 #[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("RenderTag")]
+pub struct RenderTag {
+    pub render_tag: RenderTagToken,
+    pub whitespace: WhitespaceToken,
+    pub expression: Box<swc_ecma_ast::Expr>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("IfBlock")]
+pub struct IfBlock {
+    pub open: Mustache,
+    pub children: Vec<Node>,
+    pub branches: Vec<BlockBranch>,
+    pub close: Option<Mustache>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("BlockBranch")]
+pub struct BlockBranch {
+    pub mustache: Mustache,
+    pub children: Vec<Node>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("EachBlock")]
+pub struct EachBlock {
+    pub open: Mustache,
+    pub children: Vec<Node>,
+    pub branches: Vec<BlockBranch>,
+    pub close: Option<Mustache>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("AwaitBlock")]
+pub struct AwaitBlock {
+    pub open: Mustache,
+    pub children: Vec<Node>,
+    pub branches: Vec<BlockBranch>,
+    pub close: Option<Mustache>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("KeyBlock")]
+pub struct KeyBlock {
+    pub open: Mustache,
+    pub children: Vec<Node>,
+    pub close: Option<Mustache>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("SnippetBlock")]
+pub struct SnippetBlock {
+    pub open: Mustache,
+    pub children: Vec<Node>,
+    pub close: Option<Mustache>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
 #[ast_serde("IfBlockOpen")]
 pub struct IfBlockOpen {
     pub if_open: IfOpenToken,
@@ -152,6 +801,15 @@ pub struct EachBlockOpen {
     pub span: Span,
 }
 #[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("AwaitBlockOpen")]
+pub struct AwaitBlockOpen {
+    pub await_open: AwaitOpenToken,
+    pub whitespace: WhitespaceToken,
+    pub expression: Box<swc_ecma_ast::Expr>,
+    pub await_shorthand: Option<AwaitShorthand>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
 #[ast_serde("KeyBlockOpen")]
 pub struct KeyBlockOpen {
     pub key_open: KeyOpenToken,
@@ -160,6 +818,18 @@ pub struct KeyBlockOpen {
     pub span: Span,
 }
 #[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("SnippetBlockOpen")]
+pub struct SnippetBlockOpen {
+    pub snippet_open: SnippetOpenToken,
+    pub whitespace: WhitespaceToken,
+    pub name: swc_ecma_ast::Ident,
+    pub trailing_ws: Option<WhitespaceToken>,
+    pub paren_open: ParenOpenToken,
+    pub params: Vec<swc_ecma_ast::Pat>,
+    pub paren_close: ParenCloseToken,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
 #[ast_serde("EachAs")]
 pub struct EachAs {
     pub leading_ws: WhitespaceToken,
@@ -187,3 +857,61 @@ pub struct EachKey {
     pub paren_close: ParenCloseToken,
     pub span: Span,
 }
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq, From)]
+#[ast_serde]
+pub enum AwaitShorthand {
+    #[tag("AwaitThen")]
+    AwaitThen(AwaitThen),
+    #[tag("AwaitCatch")]
+    AwaitCatch(AwaitCatch),
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("AwaitThen")]
+pub struct AwaitThen {
+    pub leading_ws: WhitespaceToken,
+    pub then: ThenToken,
+    pub trailing_ws: Option<WhitespaceToken>,
+    pub value: Option<swc_ecma_ast::Pat>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("AwaitCatch")]
+pub struct AwaitCatch {
+    pub leading_ws: WhitespaceToken,
+    pub catch: CatchToken,
+    pub trailing_ws: Option<WhitespaceToken>,
+    pub error: Option<swc_ecma_ast::Pat>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("ElseBlock")]
+pub struct ElseBlock {
+    pub else_branch: ElseBranchToken,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("ElseIfBlock")]
+pub struct ElseIfBlock {
+    pub else_branch: ElseBranchToken,
+    pub leading_ws: WhitespaceToken,
+    pub if_: IfToken,
+    pub trailing_ws: WhitespaceToken,
+    pub expression: Box<swc_ecma_ast::Expr>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("ThenBlock")]
+pub struct ThenBlock {
+    pub then_branch: ThenBranchToken,
+    pub whitespace: Option<WhitespaceToken>,
+    pub value: Option<swc_ecma_ast::Pat>,
+    pub span: Span,
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("CatchBlock")]
+pub struct CatchBlock {
+    pub catch_branch: CatchBranchToken,
+    pub whitespace: Option<WhitespaceToken>,
+    pub error: Option<swc_ecma_ast::Pat>,
+    pub span: Span,
+}
@@ -16,11 +16,11 @@ impl From<Span> for CommentEndToken {
     fn from(span: Span) -> Self { Self { span } }
 }
 #[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
-#[ast_serde("MustacheOpenToken")]
-pub struct MustacheOpenToken {
+#[ast_serde("TagOpenToken")]
+pub struct TagOpenToken {
     pub span: Span,
 }
-impl From<Span> for MustacheOpenToken {
+impl From<Span> for TagOpenToken {
     fn from(span: Span) -> Self { Self { span } }
 }
 #[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
@@ -32,6 +32,54 @@ impl From<Span> for WhitespaceToken {
     fn from(span: Span) -> Self { Self { span } }
 }
 #[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("TagCloseToken")]
+pub struct TagCloseToken {
+    pub span: Span,
+}
+impl From<Span> for TagCloseToken {
+    fn from(span: Span) -> Self { Self { span } }
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("SelfClosingTagCloseToken")]
+pub struct SelfClosingTagCloseToken {
+    pub span: Span,
+}
+impl From<Span> for SelfClosingTagCloseToken {
+    fn from(span: Span) -> Self { Self { span } }
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("EndTagOpenToken")]
+pub struct EndTagOpenToken {
+    pub span: Span,
+}
+impl From<Span> for EndTagOpenToken {
+    fn from(span: Span) -> Self { Self { span } }
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("EqToken")]
+pub struct EqToken {
+    pub span: Span,
+}
+impl From<Span> for EqToken {
+    fn from(span: Span) -> Self { Self { span } }
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("QuoteToken")]
+pub struct QuoteToken {
+    pub span: Span,
+}
+impl From<Span> for QuoteToken {
+    fn from(span: Span) -> Self { Self { span } }
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("MustacheOpenToken")]
+pub struct MustacheOpenToken {
+    pub span: Span,
+}
+impl From<Span> for MustacheOpenToken {
+    fn from(span: Span) -> Self { Self { span } }
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
 #[ast_serde("MustacheCloseToken")]
 pub struct MustacheCloseToken {
     pub span: Span,
@@ -40,6 +88,118 @@ impl From<Span> for MustacheCloseToken {
     fn from(span: Span) -> Self { Self { span } }
 }
 #[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("SpreadToken")]
+pub struct SpreadToken {
+    pub span: Span,
+}
+impl From<Span> for SpreadToken {
+    fn from(span: Span) -> Self { Self { span } }
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("ColonToken")]
+pub struct ColonToken {
+    pub span: Span,
+}
+impl From<Span> for ColonToken {
+    fn from(span: Span) -> Self { Self { span } }
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("OnToken")]
+pub struct OnToken {
+    pub span: Span,
+}
+impl From<Span> for OnToken {
+    fn from(span: Span) -> Self { Self { span } }
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("BindToken")]
+pub struct BindToken {
+    pub span: Span,
+}
+impl From<Span> for BindToken {
+    fn from(span: Span) -> Self { Self { span } }
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("ClassToken")]
+pub struct ClassToken {
+    pub span: Span,
+}
+impl From<Span> for ClassToken {
+    fn from(span: Span) -> Self { Self { span } }
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("StyleToken")]
+pub struct StyleToken {
+    pub span: Span,
+}
+impl From<Span> for StyleToken {
+    fn from(span: Span) -> Self { Self { span } }
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("UseToken")]
+pub struct UseToken {
+    pub span: Span,
+}
+impl From<Span> for UseToken {
+    fn from(span: Span) -> Self { Self { span } }
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("TransitionToken")]
+pub struct TransitionToken {
+    pub span: Span,
+}
+impl From<Span> for TransitionToken {
+    fn from(span: Span) -> Self { Self { span } }
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("InToken")]
+pub struct InToken {
+    pub span: Span,
+}
+impl From<Span> for InToken {
+    fn from(span: Span) -> Self { Self { span } }
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("OutToken")]
+pub struct OutToken {
+    pub span: Span,
+}
+impl From<Span> for OutToken {
+    fn from(span: Span) -> Self { Self { span } }
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("AnimateToken")]
+pub struct AnimateToken {
+    pub span: Span,
+}
+impl From<Span> for AnimateToken {
+    fn from(span: Span) -> Self { Self { span } }
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("LetToken")]
+pub struct LetToken {
+    pub span: Span,
+}
+impl From<Span> for LetToken {
+    fn from(span: Span) -> Self { Self { span } }
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("PipeToken")]
+pub struct PipeToken {
+    pub span: Span,
+}
+impl From<Span> for PipeToken {
+    fn from(span: Span) -> Self { Self { span } }
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("AmpersandToken")]
+pub struct AmpersandToken {
+    pub span: Span,
+}
+impl From<Span> for AmpersandToken {
+    fn from(span: Span) -> Self { Self { span } }
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
 #[ast_serde("DebugTagToken")]
 pub struct DebugTagToken {
     pub span: Span,
@@ -64,6 +224,14 @@ impl From<Span> for HtmlTagToken {
     fn from(span: Span) -> Self { Self { span } }
 }
 #[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("RenderTagToken")]
+pub struct RenderTagToken {
+    pub span: Span,
+}
+impl From<Span> for RenderTagToken {
+    fn from(span: Span) -> Self { Self { span } }
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
 #[ast_serde("KeyOpenToken")]
 pub struct KeyOpenToken {
     pub span: Span,
@@ -72,6 +240,30 @@ impl From<Span> for KeyOpenToken {
     fn from(span: Span) -> Self { Self { span } }
 }
 #[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("SnippetOpenToken")]
+pub struct SnippetOpenToken {
+    pub span: Span,
+}
+impl From<Span> for SnippetOpenToken {
+    fn from(span: Span) -> Self { Self { span } }
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("ParenOpenToken")]
+pub struct ParenOpenToken {
+    pub span: Span,
+}
+impl From<Span> for ParenOpenToken {
+    fn from(span: Span) -> Self { Self { span } }
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("ParenCloseToken")]
+pub struct ParenCloseToken {
+    pub span: Span,
+}
+impl From<Span> for ParenCloseToken {
+    fn from(span: Span) -> Self { Self { span } }
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
 #[ast_serde("IfOpenToken")]
 pub struct IfOpenToken {
     pub span: Span,
@@ -104,19 +296,59 @@ impl From<Span> for CommaToken {
     fn from(span: Span) -> Self { Self { span } }
 }
 #[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
-#[ast_serde("ParenOpenToken")]
-pub struct ParenOpenToken {
+#[ast_serde("AwaitOpenToken")]
+pub struct AwaitOpenToken {
     pub span: Span,
 }
-impl From<Span> for ParenOpenToken {
+impl From<Span> for AwaitOpenToken {
     fn from(span: Span) -> Self { Self { span } }
 }
 #[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
-#[ast_serde("ParenCloseToken")]
-pub struct ParenCloseToken {
+#[ast_serde("ThenToken")]
+pub struct ThenToken {
     pub span: Span,
 }
-impl From<Span> for ParenCloseToken {
+impl From<Span> for ThenToken {
+    fn from(span: Span) -> Self { Self { span } }
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("CatchToken")]
+pub struct CatchToken {
+    pub span: Span,
+}
+impl From<Span> for CatchToken {
+    fn from(span: Span) -> Self { Self { span } }
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("ElseBranchToken")]
+pub struct ElseBranchToken {
+    pub span: Span,
+}
+impl From<Span> for ElseBranchToken {
+    fn from(span: Span) -> Self { Self { span } }
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("IfToken")]
+pub struct IfToken {
+    pub span: Span,
+}
+impl From<Span> for IfToken {
+    fn from(span: Span) -> Self { Self { span } }
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("ThenBranchToken")]
+pub struct ThenBranchToken {
+    pub span: Span,
+}
+impl From<Span> for ThenBranchToken {
+    fn from(span: Span) -> Self { Self { span } }
+}
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("CatchBranchToken")]
+pub struct CatchBranchToken {
+    pub span: Span,
+}
+impl From<Span> for CatchBranchToken {
     fn from(span: Span) -> Self { Self { span } }
 }
 #[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
@@ -151,3 +383,11 @@ pub struct KeyCloseToken {
 impl From<Span> for KeyCloseToken {
     fn from(span: Span) -> Self { Self { span } }
 }
+#[derive(Debug, Spanned, EqIgnoreSpan, PartialEq)]
+#[ast_serde("SnippetCloseToken")]
+pub struct SnippetCloseToken {
+    pub span: Span,
+}
+impl From<Span> for SnippetCloseToken {
+    fn from(span: Span) -> Self { Self { span } }
+}
@@ -0,0 +1,200 @@
+//! HTML rules that change how elements are nested and how text is read, matching the ones the
+//! svelte parser uses.
+
+use self::entities::ENTITIES;
+
+mod entities;
+
+/// Whether `name` is a void element, which can't have children or an end tag.
+pub(crate) fn is_void_element(name: &str) -> bool {
+    matches!(
+        name,
+        "area"
+            | "base"
+            | "br"
+            | "col"
+            | "command"
+            | "embed"
+            | "hr"
+            | "img"
+            | "input"
+            | "keygen"
+            | "link"
+            | "meta"
+            | "param"
+            | "source"
+            | "track"
+            | "wbr"
+    )
+}
+
+/// Whether the content of `name` elements is raw text, which isn't parsed at all.
+pub(crate) fn is_raw_text_element(name: &str) -> bool {
+    matches!(name, "script" | "style")
+}
+
+/// Whether the content of `name` elements is escapable raw text, where mustaches are parsed but
+/// tags aren't.
+pub(crate) fn is_escapable_raw_text_element(name: &str) -> bool {
+    matches!(name, "textarea" | "title")
+}
+
+/// Whether an open `current` element is closed implicitly when a `next` element is opened inside
+/// of it, like `<li>` by another `<li>`. When `next` is `None`, this is whether `current` is closed
+/// implicitly by its parent's end tag.
+pub(crate) fn closing_tag_omitted(current: &str, next: Option<&str>) -> bool {
+    let disallowed: &[&str] = match current {
+        "li" => &["li"],
+        "dt" | "dd" => &["dt", "dd"],
+        "p" => &[
+            "address",
+            "article",
+            "aside",
+            "blockquote",
+            "div",
+            "dl",
+            "fieldset",
+            "footer",
+            "form",
+            "h1",
+            "h2",
+            "h3",
+            "h4",
+            "h5",
+            "h6",
+            "header",
+            "hgroup",
+            "hr",
+            "main",
+            "menu",
+            "nav",
+            "ol",
+            "p",
+            "pre",
+            "section",
+            "table",
+            "ul",
+        ],
+        "rt" | "rp" => &["rt", "rp"],
+        "optgroup" => &["optgroup"],
+        "option" => &["option", "optgroup"],
+        "thead" | "tbody" => &["tbody", "tfoot"],
+        "tfoot" => &["tbody"],
+        "tr" => &["tr", "tbody"],
+        "td" | "th" => &["td", "th", "tr"],
+        _ => return false,
+    };
+
+    next.is_none_or(|next| disallowed.contains(&next))
+}
+
+/// Decodes character references like `&amp;`, `&#x27;` and the legacy `&nbsp` without a `;`.
+/// In attribute values, a legacy reference followed by `=` or an alphanumeric character is left
+/// alone, like HTML does.
+pub(crate) fn decode_character_references(text: &str, is_attribute_value: bool) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(index) = rest.find('&') {
+        decoded.push_str(&rest[..index]);
+        rest = &rest[index + 1..];
+        match decode_reference(rest, is_attribute_value, &mut decoded) {
+            Some(len) => rest = &rest[len..],
+            None => decoded.push('&'),
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Decodes the reference at the start of `text`, which comes just after a `&`, into `decoded`.
+/// Returns how much of `text` was part of the reference.
+fn decode_reference(text: &str, is_attribute_value: bool, decoded: &mut String) -> Option<usize> {
+    if let Some(number) = text.strip_prefix('#') {
+        let (radix, digits_start) = match number.as_bytes().first() {
+            Some(b'x' | b'X') => (16, 2),
+            _ => (10, 1),
+        };
+        let digits = text[digits_start..]
+            .chars()
+            .take_while(|c| c.is_digit(radix))
+            .count();
+        if digits == 0 {
+            return None;
+        }
+
+        let mut len = digits_start + digits;
+        let code = u32::from_str_radix(&text[digits_start..len], radix).unwrap_or(u32::MAX);
+        if text[len..].starts_with(';') {
+            len += 1;
+        }
+        decoded.push(numeric_reference_char(code));
+        return Some(len);
+    }
+
+    let name_len = text
+        .bytes()
+        .take_while(|byte| byte.is_ascii_alphanumeric())
+        .count();
+    if text[name_len..].starts_with(';') {
+        if let Some(value) = named_reference(&text[..=name_len]) {
+            decoded.push_str(value);
+            return Some(name_len + 1);
+        }
+    }
+
+    // Legacy references can be followed directly by other text, like `&copy2024`.
+    let (len, value) = (1..=name_len)
+        .rev()
+        .find_map(|len| Some((len, named_reference(&text[..len])?)))?;
+    if is_attribute_value
+        && text[len..].starts_with(|c: char| c == '=' || c.is_ascii_alphanumeric())
+    {
+        return None;
+    }
+    decoded.push_str(value);
+    Some(len)
+}
+
+fn named_reference(name: &str) -> Option<&'static str> {
+    let index = ENTITIES
+        .binary_search_by_key(&name, |(name, _)| name)
+        .ok()?;
+    Some(ENTITIES[index].1)
+}
+
+/// The character for a numeric reference. Invalid code points become the replacement character,
+/// and the C1 control range is read as windows-1252, which is what those references usually mean.
+fn numeric_reference_char(code: u32) -> char {
+    let code = match code {
+        0x80 => 0x20ac,
+        0x82 => 0x201a,
+        0x83 => 0x0192,
+        0x84 => 0x201e,
+        0x85 => 0x2026,
+        0x86 => 0x2020,
+        0x87 => 0x2021,
+        0x88 => 0x02c6,
+        0x89 => 0x2030,
+        0x8a => 0x0160,
+        0x8b => 0x2039,
+        0x8c => 0x0152,
+        0x8e => 0x017d,
+        0x91 => 0x2018,
+        0x92 => 0x2019,
+        0x93 => 0x201c,
+        0x94 => 0x201d,
+        0x95 => 0x2022,
+        0x96 => 0x2013,
+        0x97 => 0x2014,
+        0x98 => 0x02dc,
+        0x99 => 0x2122,
+        0x9a => 0x0161,
+        0x9b => 0x203a,
+        0x9c => 0x0153,
+        0x9e => 0x017e,
+        0x9f => 0x0178,
+        0 => return char::REPLACEMENT_CHARACTER,
+        code => code,
+    };
+    char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER)
+}
@@ -0,0 +1,90 @@
+//! `<!-- svelte-ignore ... -->` comments, which silence warnings for the node after them and
+//! everything inside it.
+
+use crate::syntax_nodes::Node;
+
+/// Warning codes from before svelte 5 that were renamed rather than just having their dashes
+/// replaced with underscores.
+const RENAMED_CODES: &[(&str, &str)] = &[
+    (
+        "non-top-level-reactive-declaration",
+        "reactive_declaration_invalid_placement",
+    ),
+    (
+        "module-script-reactive-declaration",
+        "reactive_declaration_module_script",
+    ),
+    ("empty-block", "block_empty"),
+    ("avoid-is", "attribute_avoid_is"),
+    ("invalid-html-attribute", "attribute_invalid_property_name"),
+    ("a11y-structure", "a11y_figcaption_parent"),
+    ("illegal-attribute-character", "attribute_illegal_colon"),
+    ("invalid-rest-eachblock-binding", "bind_invalid_each_rest"),
+    ("unused-export-let", "export_let_unused"),
+];
+
+/// The warning codes ignored by a comment, given the text between its `<!--` and `-->`.
+///
+/// In runes mode the codes have to be separated by commas, and anything after the last one is a
+/// description. Otherwise every word is taken as a code, and old codes like `empty-block` also
+/// ignore their new names.
+pub fn svelte_ignore_codes(comment: &str, runes: bool) -> Vec<String> {
+    let trimmed = comment.trim_start();
+    let Some(rest) = trimmed.strip_prefix("svelte-ignore") else {
+        return Vec::new();
+    };
+    if !rest.starts_with(char::is_whitespace) {
+        return Vec::new();
+    }
+
+    let is_code_char = |c: char| c.is_ascii_alphanumeric() || matches!(c, '_' | '$' | '-');
+    let mut codes = Vec::new();
+    let mut rest = rest;
+    while let Some(start) = rest.find(is_code_char) {
+        rest = &rest[start..];
+        let end = rest.find(|c| !is_code_char(c)).unwrap_or(rest.len());
+        let code = &rest[..end];
+        rest = &rest[end..];
+
+        if runes {
+            if !codes.iter().any(|ignored| ignored == code) {
+                codes.push(code.to_string());
+            }
+            if !rest.starts_with(',') {
+                break;
+            }
+        } else {
+            codes.push(code.to_string());
+            let renamed = RENAMED_CODES
+                .iter()
+                .find(|(old, _)| *old == code)
+                .map_or_else(|| code.replace('-', "_"), |(_, new)| new.to_string());
+            if renamed != code {
+                codes.push(renamed);
+            }
+        }
+    }
+    codes
+}
+
+/// The warning codes ignored for a node by the `svelte-ignore` comments just before it, given
+/// the siblings that come before the node.
+///
+/// Text between the comments and the node doesn't stop them applying, and neither do scripts and
+/// styles, which svelte takes out of the template. Svelte doesn't apply them to text or comments.
+pub fn ignored_codes(preceding_siblings: &[Node], runes: bool) -> Vec<String> {
+    let mut codes = Vec::new();
+    for sibling in preceding_siblings.iter().rev() {
+        match sibling {
+            Node::Comment(comment) => {
+                codes.extend(svelte_ignore_codes(&comment.comment_text.text, runes));
+            }
+            Node::Text(..)
+            | Node::InstanceScript(..)
+            | Node::ModuleScript(..)
+            | Node::Style(..) => {}
+            _ => break,
+        }
+    }
+    codes
+}
@@ -1,9 +1,15 @@
 //! WIP svelte parser. Not ready for use yet.
 
 pub mod error;
+pub mod fix;
 mod generated;
+mod html;
+pub mod ignore;
+pub mod options;
 pub mod parser;
+pub mod render;
 mod state;
+mod syntax;
 pub use swc_ecma_ast as ecma;
 
 #[cfg(target_arch = "wasm32")]
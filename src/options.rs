@@ -0,0 +1,218 @@
+use crate::{
+    error::ParseErrorKind,
+    parser::Parser,
+    syntax_nodes::{AttributeItem, AttributeValue, AttributeValuePart, StartTag},
+};
+use serde::{Deserialize, Serialize};
+use swc_common::{Span, Spanned};
+use swc_ecma_ast::{Expr, Lit, ObjectLit, Prop, PropName, PropOrSpread};
+
+/// Compiler options set by a component with `<svelte:options>`. Options that weren't set are
+/// `None`.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SvelteOptions {
+    pub runes: Option<bool>,
+    pub immutable: Option<bool>,
+    pub accessors: Option<bool>,
+    pub preserve_whitespace: Option<bool>,
+    pub namespace: Option<Namespace>,
+    pub css: Option<Css>,
+    pub custom_element: Option<CustomElement>,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Namespace {
+    Html,
+    Svg,
+    Mathml,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Css {
+    Injected,
+}
+
+/// Set with `customElement="my-tag"`, or `customElement={{ tag: "my-tag", shadow: "none" }}`.
+/// Other properties of the object form aren't static, so they aren't included.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomElement {
+    pub tag: Option<String>,
+    pub shadow: Option<ShadowMode>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ShadowMode {
+    Open,
+    None,
+}
+
+impl SvelteOptions {
+    /// Reads options from the attributes of `<svelte:options>`, reporting any that are unknown or
+    /// don't have a valid static value.
+    pub(crate) fn from_start_tag(parser: &mut Parser<'_>, start_tag: &StartTag) -> Self {
+        let mut options = SvelteOptions {
+            span: start_tag.span,
+            ..Default::default()
+        };
+
+        for attribute in &start_tag.attributes {
+            let AttributeItem::HtmlAttribute(attribute) = &attribute.attribute_item else {
+                parser.error_with_span(
+                    ParseErrorKind::UnknownSvelteOption,
+                    attribute.attribute_item.span(),
+                );
+                continue;
+            };
+            let value = attribute
+                .attribute_initializer
+                .as_ref()
+                .map(|initializer| &initializer.attribute_value);
+
+            let is_valid = match attribute.attribute_name.text.as_str() {
+                "runes" => {
+                    options.runes = bool_value(value);
+                    options.runes.is_some()
+                }
+                "immutable" => {
+                    options.immutable = bool_value(value);
+                    options.immutable.is_some()
+                }
+                "accessors" => {
+                    options.accessors = bool_value(value);
+                    options.accessors.is_some()
+                }
+                "preserveWhitespace" => {
+                    options.preserve_whitespace = bool_value(value);
+                    options.preserve_whitespace.is_some()
+                }
+                "namespace" => {
+                    options.namespace = match static_value(value) {
+                        Some(StaticValue::Str("html")) => Some(Namespace::Html),
+                        Some(StaticValue::Str("svg")) => Some(Namespace::Svg),
+                        Some(StaticValue::Str("mathml")) => Some(Namespace::Mathml),
+                        _ => None,
+                    };
+                    options.namespace.is_some()
+                }
+                "css" => {
+                    options.css = match static_value(value) {
+                        Some(StaticValue::Str("injected")) => Some(Css::Injected),
+                        _ => None,
+                    };
+                    options.css.is_some()
+                }
+                "customElement" => {
+                    options.custom_element = custom_element_value(value);
+                    options.custom_element.is_some()
+                }
+                _ => {
+                    parser.error_with_span(
+                        ParseErrorKind::UnknownSvelteOption,
+                        attribute.attribute_name.span,
+                    );
+                    continue;
+                }
+            };
+
+            if !is_valid {
+                parser.error_with_span(
+                    ParseErrorKind::InvalidSvelteOptionValue,
+                    value.map_or(attribute.span, |value| value.span),
+                );
+            }
+        }
+
+        options
+    }
+}
+
+enum StaticValue<'a> {
+    Bool(bool),
+    Str(&'a str),
+    Object(&'a ObjectLit),
+}
+
+/// Gets the value of an attribute if it can be known without running any code. An attribute
+/// without a value is `true`.
+fn static_value(value: Option<&AttributeValue>) -> Option<StaticValue<'_>> {
+    let Some(value) = value else {
+        return Some(StaticValue::Bool(true));
+    };
+
+    match &value.parts[..] {
+        [] => Some(StaticValue::Str("")),
+        [AttributeValuePart::Text(text)] => Some(StaticValue::Str(&text.data)),
+        [AttributeValuePart::AttributeMustache(mustache)] => match &*mustache.expression {
+            Expr::Lit(Lit::Bool(value)) => Some(StaticValue::Bool(value.value)),
+            Expr::Lit(Lit::Str(value)) => Some(StaticValue::Str(&value.value)),
+            Expr::Object(object) => Some(StaticValue::Object(object)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn bool_value(value: Option<&AttributeValue>) -> Option<bool> {
+    match static_value(value) {
+        Some(StaticValue::Bool(value)) => Some(value),
+        _ => None,
+    }
+}
+
+fn custom_element_value(value: Option<&AttributeValue>) -> Option<CustomElement> {
+    let custom_element = match static_value(value)? {
+        StaticValue::Str(tag) => CustomElement {
+            tag: Some(tag.to_string()),
+            shadow: None,
+        },
+        StaticValue::Object(object) => {
+            let mut custom_element = CustomElement::default();
+            for prop in &object.props {
+                let PropOrSpread::Prop(prop) = prop else {
+                    return None;
+                };
+                let Prop::KeyValue(prop) = &**prop else {
+                    continue;
+                };
+                let key = match &prop.key {
+                    PropName::Ident(ident) => &*ident.sym,
+                    PropName::Str(str) => &*str.value,
+                    _ => continue,
+                };
+                let value = match &*prop.value {
+                    Expr::Lit(Lit::Str(value)) => Some(&*value.value),
+                    _ => None,
+                };
+
+                match (key, value) {
+                    ("tag", Some(tag)) => custom_element.tag = Some(tag.to_string()),
+                    ("shadow", Some("open")) => custom_element.shadow = Some(ShadowMode::Open),
+                    ("shadow", Some("none")) => custom_element.shadow = Some(ShadowMode::None),
+                    ("tag" | "shadow", _) => return None,
+                    _ => {}
+                }
+            }
+            custom_element
+        }
+        StaticValue::Bool(..) => return None,
+    };
+
+    match &custom_element.tag {
+        Some(tag) if !is_valid_custom_element_name(tag) => None,
+        _ => Some(custom_element),
+    }
+}
+
+/// Custom element names have to start with a lowercase letter and contain a hyphen.
+fn is_valid_custom_element_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name.contains('-')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '-' | '.' | '_'))
+}
@@ -1,33 +1,174 @@
 use crate::{
-    error::{ErrorReporter, ParseError, ParseErrorKind},
-    state::{State, StateTransition},
-    syntax_nodes::Node,
+    error::{Diagnostic, DiagnosticTag, ErrorReporter, ParseError, ParseErrorKind, Severity},
+    fix::Fix,
+    html::{closing_tag_omitted, is_escapable_raw_text_element, is_void_element},
+    ignore::ignored_codes,
+    options::SvelteOptions,
+    state::{is_attribute_name_end, is_unquoted_value_end, State, StateTransition},
+    syntax_nodes::{
+        AttributeItem, AwaitBlock, BlockBranch, DirectiveKind, EachBlock, Element, EndTag, IfBlock,
+        KeyBlock, Mustache, Node, Slot, SnippetBlock, StartTag, SvelteBody, SvelteComponent,
+        SvelteDocument, SvelteElement, SvelteFragment, SvelteHead, SvelteOptionsElement,
+        SvelteSelf, SvelteWindow,
+    },
     tokens::WhitespaceToken,
 };
 use muncher::Muncher;
-use swc_common::{BytePos, Span};
+use serde::{Deserialize, Serialize};
+use std::{
+    cell::Cell,
+    mem,
+    panic::{self, AssertUnwindSafe},
+    sync::Once,
+};
+use swc_common::{BytePos, Span, Spanned};
+use swc_ecma_ast::{EsVersion, Ident};
+use swc_ecma_parser::{lexer::Lexer, EsConfig, PResult, StringInput, Syntax, TsConfig};
+
+/// The result of parsing a whole component.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Component {
+    pub nodes: Vec<Node>,
+    /// Options from the component's `<svelte:options>`, if it has one.
+    pub options: Option<SvelteOptions>,
+}
 
 pub struct Parser<'a> {
     muncher: Muncher<'a>,
     nodes: Vec<Node>,
+    open_nodes: Vec<OpenNode>,
+    options: Option<SvelteOptions>,
+    /// The last element that was closed implicitly by a start tag, so that its end tag can be
+    /// reported with a better error.
+    last_auto_closed: Option<AutoClosed>,
+    typescript: bool,
     error_reporter: &'a mut dyn ErrorReporter,
 }
 
+struct AutoClosed {
+    tag_name: String,
+    /// How many nodes were open after closing it. Once fewer are open, an end tag with the same
+    /// name can't be meant for it anymore.
+    depth: usize,
+}
+
+/// An element or block that has been opened but not closed yet. Nodes are pushed into the
+/// innermost one.
+enum OpenNode {
+    Element(OpenElement),
+    Block(Box<OpenBlock>),
+}
+
+impl OpenNode {
+    /// The children that nodes are pushed into, which are in the last branch of a block with
+    /// branches.
+    fn children(&self) -> &[Node] {
+        match self {
+            OpenNode::Element(element) => &element.children,
+            OpenNode::Block(block) => match block.branches.last() {
+                Some(branch) => &branch.children,
+                None => &block.children,
+            },
+        }
+    }
+}
+
+/// An element whose start tag has been parsed but whose end tag hasn't been found yet.
+struct OpenElement {
+    start_tag: StartTag,
+    children: Vec<Node>,
+}
+
+/// A block whose `{#...}` has been parsed but whose `{/...}` hasn't been found yet.
+struct OpenBlock {
+    kind: BlockKind,
+    open: Mustache,
+    children: Vec<Node>,
+    branches: Vec<BlockBranch>,
+    /// The kinds of `branches`, along with a `then` or `catch` from the `{#await}` itself.
+    branch_kinds: Vec<BranchKind>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BlockKind {
+    If,
+    Each,
+    Await,
+    Key,
+    Snippet,
+}
+
+impl BlockKind {
+    /// The name used in the block's tags, like `if` in `{#if}`.
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            BlockKind::If => "if",
+            BlockKind::Each => "each",
+            BlockKind::Await => "await",
+            BlockKind::Key => "key",
+            BlockKind::Snippet => "snippet",
+        }
+    }
+}
+
+/// The kinds of `{:...}` tags that can continue a block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BranchKind {
+    Else,
+    ElseIf,
+    Then,
+    Catch,
+}
+
 impl<'a> Parser<'a> {
     pub fn new(source: &'a str, error_reporter: &'a mut dyn ErrorReporter) -> Self {
         Self {
             muncher: Muncher::new(source),
             nodes: Default::default(),
+            open_nodes: Default::default(),
+            options: None,
+            last_auto_closed: None,
+            typescript: has_typescript_script(source),
             error_reporter,
         }
     }
 
-    pub fn parse(mut self) -> Vec<Node> {
+    /// Parse scripts and template expressions as TypeScript (or not), instead of detecting it from
+    /// a `lang="ts"` attribute on a `<script>`.
+    pub fn with_typescript(mut self, typescript: bool) -> Self {
+        self.typescript = typescript;
+        self
+    }
+
+    pub fn parse(self) -> Vec<Node> {
+        self.parse_component().nodes
+    }
+
+    pub fn parse_component(mut self) -> Component {
         let mut state = State::default();
         while !state.is_eof() {
             state = state.next_state(&mut self);
         }
-        self.nodes
+        while !self.open_nodes.is_empty() {
+            self.finish_unclosed();
+        }
+
+        Component {
+            nodes: self.nodes,
+            options: self.options,
+        }
+    }
+
+    pub(crate) fn is_typescript(&self) -> bool {
+        self.typescript
+    }
+
+    pub(crate) fn syntax(&self) -> Syntax {
+        if self.typescript {
+            Syntax::Typescript(TsConfig::default())
+        } else {
+            Syntax::Es(EsConfig::default())
+        }
     }
 
     pub(crate) fn peek(&self) -> Option<&char> {
@@ -37,6 +178,21 @@ impl<'a> Parser<'a> {
         peek
     }
 
+    pub(crate) fn peek_chars(&self, s: &str) -> bool {
+        self.muncher.reset_peek();
+        let matches = s.chars().all(|c| self.muncher.peek() == Some(&c));
+        self.muncher.reset_peek();
+        matches
+    }
+
+    /// Returns `true` if the next word, after any whitespace, is `keyword`.
+    pub(crate) fn peek_keyword(&self, keyword: &str) -> bool {
+        self.text()[self.position()..]
+            .trim_start_matches(|c: char| c.is_ascii_whitespace())
+            .strip_prefix(keyword)
+            .is_some_and(|rest| !rest.starts_with(Ident::is_valid_continue))
+    }
+
     pub(crate) fn peek_ignore_whitespace(&self) -> Option<&char> {
         let _ = self.muncher.peek_until(|c| !c.is_ascii_whitespace());
         let peek = self.muncher.peek();
@@ -53,7 +209,561 @@ impl<'a> Parser<'a> {
     }
 
     pub(crate) fn push_node(&mut self, node: impl Into<Node>) {
-        self.nodes.push(node.into())
+        let node = node.into();
+        match self.open_nodes.last_mut() {
+            Some(OpenNode::Element(element)) => element.children.push(node),
+            Some(OpenNode::Block(block)) => match block.branches.last_mut() {
+                Some(branch) => branch.children.push(node),
+                None => block.children.push(node),
+            },
+            None => self.nodes.push(node),
+        }
+    }
+
+    /// Returns `true` if nodes are currently being pushed to the root of the component, rather than
+    /// into an element or block.
+    pub(crate) fn is_top_level(&self) -> bool {
+        self.open_nodes.is_empty()
+    }
+
+    pub(crate) fn top_level_nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    /// The start of the end tag, like `</textarea`, that ends the innermost open element if its
+    /// content is escapable raw text.
+    pub(crate) fn escapable_raw_text_end_tag(&self) -> Option<String> {
+        match self.open_nodes.last() {
+            Some(OpenNode::Element(element))
+                if is_escapable_raw_text_element(&element.start_tag.tag_name.text) =>
+            {
+                Some(format!("</{}", element.start_tag.tag_name.text))
+            }
+            _ => None,
+        }
+    }
+
+    /// The kind of the innermost block that is still open, even if there are elements open inside
+    /// it.
+    pub(crate) fn innermost_block(&self) -> Option<BlockKind> {
+        self.open_nodes.iter().rev().find_map(|node| match node {
+            OpenNode::Block(block) => Some(block.kind),
+            OpenNode::Element(..) => None,
+        })
+    }
+
+    /// Whether a `{@const}` pushed now would be the immediate child of something that allows it:
+    /// an if, each or snippet block, the `{:then}` or `{:catch}` branch of an await block, a
+    /// component, or `<svelte:fragment>`.
+    pub(crate) fn can_contain_const_tag(&self) -> bool {
+        match self.open_nodes.last() {
+            Some(OpenNode::Element(element)) => {
+                let tag_name = element.start_tag.tag_name.text.as_str();
+                is_component(tag_name) || tag_name == "svelte:fragment"
+            }
+            Some(OpenNode::Block(block)) => match block.kind {
+                BlockKind::If | BlockKind::Each | BlockKind::Snippet => true,
+                BlockKind::Await => matches!(
+                    block.branch_kinds.last(),
+                    Some(BranchKind::Then | BranchKind::Catch)
+                ),
+                BlockKind::Key => false,
+            },
+            None => false,
+        }
+    }
+
+    /// Closes the innermost open element if HTML allows its end tag to be left out before a
+    /// `tag_name` element, like a `<li>` before another `<li>`.
+    pub(crate) fn close_omitted_element(&mut self, tag_name: &str) {
+        let Some(OpenNode::Element(element)) = self.open_nodes.last() else {
+            return;
+        };
+        if !closing_tag_omitted(&element.start_tag.tag_name.text, Some(tag_name)) {
+            return;
+        }
+        if let Some(OpenNode::Element(element)) = self.open_nodes.pop() {
+            self.last_auto_closed = Some(AutoClosed {
+                tag_name: element.start_tag.tag_name.text.clone(),
+                depth: self.open_nodes.len(),
+            });
+            self.push_element(element.start_tag, element.children, None);
+        }
+    }
+
+    /// Start collecting children for the element opened by `start_tag`, until a matching end tag
+    /// is found or the input ends.
+    pub(crate) fn open_element(&mut self, start_tag: StartTag) {
+        self.open_nodes.push(OpenNode::Element(OpenElement {
+            start_tag,
+            children: Default::default(),
+        }));
+    }
+
+    /// Close the nearest open element with the same name as `end_tag`. Any elements or blocks
+    /// opened inside of it are closed too. Like in svelte, regular elements are closed silently,
+    /// while blocks, components and `svelte:*` elements are reported as not being closed.
+    pub(crate) fn close_element(&mut self, end_tag: EndTag) {
+        let name = &end_tag.tag_name.text;
+        if is_void_element(name) {
+            self.report_error(
+                ParseError::new(ParseErrorKind::InvalidVoidElementContent, end_tag.span)
+                    .with_fix(Fix::delete(format!("Remove `</{name}>`"), end_tag.span)),
+            );
+            self.push_node(end_tag);
+            return;
+        }
+
+        let Some(index) = self.open_nodes.iter().rposition(|node| {
+            matches!(node, OpenNode::Element(element) if &element.start_tag.tag_name.text == name)
+        }) else {
+            let kind = match &self.last_auto_closed {
+                Some(auto_closed)
+                    if &auto_closed.tag_name == name
+                        && self.open_nodes.len() >= auto_closed.depth =>
+                {
+                    ParseErrorKind::AutoClosedElementEndTag
+                }
+                _ => ParseErrorKind::UnexpectedEndTag,
+            };
+            self.error_with_span(kind, end_tag.span);
+            self.push_node(end_tag);
+            return;
+        };
+
+        while self.open_nodes.len() > index + 1 {
+            match self.open_nodes.pop() {
+                Some(OpenNode::Element(element))
+                    if is_regular_element(&element.start_tag.tag_name.text) =>
+                {
+                    self.push_element(element.start_tag, element.children, None);
+                }
+                Some(OpenNode::Element(element)) => self.finish_element(element, None),
+                Some(OpenNode::Block(block)) => self.finish_block(*block, None),
+                None => {}
+            }
+        }
+        if let Some(OpenNode::Element(element)) = self.open_nodes.pop() {
+            self.finish_element(element, Some(end_tag));
+        }
+    }
+
+    /// Start collecting children for the block opened by `open`, until a matching `{/...}` is found
+    /// or the input ends. `branch` is set for `{#await promise then value}` and similar, which
+    /// start in a branch.
+    pub(crate) fn open_block(
+        &mut self,
+        kind: BlockKind,
+        branch: Option<BranchKind>,
+        open: Mustache,
+    ) {
+        self.open_nodes.push(OpenNode::Block(Box::new(OpenBlock {
+            kind,
+            open,
+            children: Default::default(),
+            branches: Default::default(),
+            branch_kinds: branch.into_iter().collect(),
+        })));
+    }
+
+    /// Starts a new branch of the innermost open block with a `{:...}` tag, if it's allowed there
+    /// given the branches that block already has. Otherwise the tag is reported and kept as a
+    /// child.
+    pub(crate) fn continue_block(&mut self, kind: BranchKind, mustache: Mustache) {
+        let span = mustache.mustache_item.span();
+        if !self
+            .open_nodes
+            .iter()
+            .any(|node| matches!(node, OpenNode::Block(..)))
+        {
+            self.error_with_span(ParseErrorKind::BlockContinuationOutsideBlock, span);
+            self.push_node(mustache);
+            return;
+        }
+
+        let Some(OpenNode::Block(block)) = self.open_nodes.last_mut() else {
+            // The block has an element inside of it that still needs to be closed.
+            self.error_with_span(ParseErrorKind::InvalidBlockContinuation, span);
+            self.push_node(mustache);
+            return;
+        };
+
+        let has = |kind| block.branch_kinds.contains(&kind);
+        let is_allowed = match kind {
+            BranchKind::Else => {
+                matches!(block.kind, BlockKind::If | BlockKind::Each) && !has(BranchKind::Else)
+            }
+            BranchKind::ElseIf => block.kind == BlockKind::If && !has(BranchKind::Else),
+            BranchKind::Then => {
+                block.kind == BlockKind::Await && !has(BranchKind::Then) && !has(BranchKind::Catch)
+            }
+            BranchKind::Catch => block.kind == BlockKind::Await && !has(BranchKind::Catch),
+        };
+
+        if is_allowed {
+            block.branch_kinds.push(kind);
+            block.branches.push(BlockBranch {
+                span: mustache.span,
+                mustache,
+                children: Default::default(),
+            });
+        } else {
+            self.error_with_span(ParseErrorKind::InvalidBlockContinuation, span);
+            self.push_node(mustache);
+        }
+    }
+
+    /// Close the nearest open block of the same kind, along with any elements or blocks opened
+    /// inside of it, which are reported as not being closed.
+    pub(crate) fn close_block(&mut self, kind: BlockKind, close: Mustache) {
+        let Some(index) = self
+            .open_nodes
+            .iter()
+            .rposition(|node| matches!(node, OpenNode::Block(block) if block.kind == kind))
+        else {
+            self.error_with_span(
+                ParseErrorKind::UnexpectedBlockClose,
+                close.mustache_item.span(),
+            );
+            self.push_node(close);
+            return;
+        };
+
+        while self.open_nodes.len() > index + 1 {
+            self.finish_unclosed();
+        }
+        if let Some(OpenNode::Block(block)) = self.open_nodes.pop() {
+            self.finish_block(*block, Some(close));
+        }
+    }
+
+    /// Finish the innermost open element or block without an end tag or close.
+    fn finish_unclosed(&mut self) {
+        match self.open_nodes.pop() {
+            Some(OpenNode::Element(element)) => self.finish_element(element, None),
+            Some(OpenNode::Block(block)) => self.finish_block(*block, None),
+            None => {}
+        }
+    }
+
+    fn finish_element(&mut self, element: OpenElement, end_tag: Option<EndTag>) {
+        if end_tag.is_none() {
+            self.error_with_span(ParseErrorKind::ElementNotClosed, element.start_tag.span);
+        }
+        self.push_element(element.start_tag, element.children, end_tag);
+    }
+
+    /// Pushes a finished element, as one of the `svelte:*` special elements if it is one.
+    pub(crate) fn push_element(
+        &mut self,
+        start_tag: StartTag,
+        children: Vec<Node>,
+        end_tag: Option<EndTag>,
+    ) {
+        let hi = match (&end_tag, children.last()) {
+            (Some(end_tag), _) => end_tag.span_hi(),
+            (None, Some(child)) => child.span_hi(),
+            (None, None) => start_tag.span_hi(),
+        };
+        let span = start_tag.span.with_hi(hi);
+        self.validate_special_element(&start_tag, &children);
+        self.validate_slots(&start_tag, &children);
+
+        let node: Node = match start_tag.tag_name.text.as_str() {
+            "svelte:self" => SvelteSelf {
+                start_tag,
+                children,
+                end_tag,
+                span,
+            }
+            .into(),
+            "svelte:component" => SvelteComponent {
+                start_tag,
+                children,
+                end_tag,
+                span,
+            }
+            .into(),
+            "svelte:element" => SvelteElement {
+                start_tag,
+                children,
+                end_tag,
+                span,
+            }
+            .into(),
+            "svelte:window" => SvelteWindow {
+                start_tag,
+                children,
+                end_tag,
+                span,
+            }
+            .into(),
+            "svelte:body" => SvelteBody {
+                start_tag,
+                children,
+                end_tag,
+                span,
+            }
+            .into(),
+            "svelte:document" => SvelteDocument {
+                start_tag,
+                children,
+                end_tag,
+                span,
+            }
+            .into(),
+            "svelte:head" => SvelteHead {
+                start_tag,
+                children,
+                end_tag,
+                span,
+            }
+            .into(),
+            "svelte:fragment" => SvelteFragment {
+                start_tag,
+                children,
+                end_tag,
+                span,
+            }
+            .into(),
+            "slot" => Slot {
+                start_tag,
+                children,
+                end_tag,
+                span,
+            }
+            .into(),
+            "svelte:options" => {
+                if self.is_top_level() && self.options.is_none() {
+                    self.options = Some(SvelteOptions::from_start_tag(self, &start_tag));
+                }
+                SvelteOptionsElement {
+                    start_tag,
+                    children,
+                    end_tag,
+                    span,
+                }
+                .into()
+            }
+            _ => Element {
+                start_tag,
+                children,
+                end_tag,
+                span,
+            }
+            .into(),
+        };
+
+        if is_svelte_meta_tag(&node) {
+            if !self.is_top_level() {
+                self.error_with_span(ParseErrorKind::InvalidSvelteMetaPlacement, span);
+            } else if self
+                .nodes
+                .iter()
+                .any(|other| mem::discriminant(other) == mem::discriminant(&node))
+            {
+                self.error_with_span(ParseErrorKind::DuplicateSvelteMeta, span);
+            }
+        }
+        self.push_node(node);
+    }
+
+    fn validate_special_element(&mut self, start_tag: &StartTag, children: &[Node]) {
+        let Some(name) = start_tag.tag_name.text.strip_prefix("svelte:") else {
+            return;
+        };
+
+        match name {
+            "component" | "element" => {
+                let has_this = start_tag.attributes.iter().any(|attribute| {
+                    matches!(
+                        &attribute.attribute_item,
+                        AttributeItem::HtmlAttribute(attribute)
+                            if attribute.attribute_name.text == "this"
+                                && attribute.attribute_initializer.is_some()
+                    )
+                });
+                if !has_this {
+                    self.error_with_span(ParseErrorKind::MissingThisAttribute, start_tag.span);
+                }
+            }
+            "window" | "body" | "document" | "options" => {
+                if !children.is_empty() {
+                    self.error_with_span(ParseErrorKind::InvalidSvelteMetaContent, start_tag.span);
+                }
+            }
+            "self" | "head" | "fragment" => {}
+            _ => self.error_with_span(ParseErrorKind::UnknownSvelteTag, start_tag.tag_name.span),
+        }
+    }
+
+    /// Checks `<slot>` elements, and that `slot="..."` and `let:` are only used where something
+    /// can be passed to a component's slot.
+    fn validate_slots(&mut self, start_tag: &StartTag, children: &[Node]) {
+        let tag_name = start_tag.tag_name.text.as_str();
+        let is_slot = tag_name == "slot";
+
+        if is_slot {
+            if let Some(name) = start_tag.attribute("name") {
+                let is_valid = name
+                    .attribute_initializer
+                    .as_ref()
+                    .and_then(|initializer| initializer.attribute_value.static_text())
+                    .is_some_and(|name| name != "default");
+                if !is_valid {
+                    self.error_with_span(ParseErrorKind::InvalidSlotName, name.span);
+                }
+            }
+            for attribute in &start_tag.attributes {
+                if let AttributeItem::Directive(directive) = &attribute.attribute_item {
+                    if !matches!(directive.directive_kind, DirectiveKind::Let(..)) {
+                        self.error_with_span(
+                            ParseErrorKind::InvalidSlotElementAttribute,
+                            directive.span,
+                        );
+                    }
+                }
+            }
+        }
+
+        let slot_attribute = start_tag.attribute("slot");
+        if let Some(attribute) = slot_attribute {
+            let is_static = attribute
+                .attribute_initializer
+                .as_ref()
+                .is_some_and(|initializer| initializer.attribute_value.static_text().is_some());
+            if !is_static {
+                self.error_with_span(ParseErrorKind::DynamicSlotAttribute, attribute.span);
+            }
+            if !self.can_fill_slot() {
+                self.error_with_span(
+                    ParseErrorKind::InvalidSlotAttributePlacement,
+                    attribute.span,
+                );
+            }
+        }
+
+        let receives_slot_props = is_slot
+            || slot_attribute.is_some()
+            || is_component(tag_name)
+            || tag_name == "svelte:fragment";
+        if !receives_slot_props {
+            for directive in start_tag.let_directives() {
+                self.error_with_span(ParseErrorKind::InvalidLetDirectivePlacement, directive.span);
+            }
+        }
+
+        if is_component(tag_name) {
+            let mut slot_names = Vec::new();
+            for start_tag in children.iter().filter_map(node_start_tag) {
+                let (Some(name), Some(attribute)) =
+                    (start_tag.slot_name(), start_tag.attribute("slot"))
+                else {
+                    continue;
+                };
+                if slot_names.contains(&name) {
+                    self.error_with_span(ParseErrorKind::DuplicateSlotAttribute, attribute.span);
+                } else {
+                    slot_names.push(name);
+                }
+            }
+        }
+    }
+
+    /// Whether an element being pushed now can have a `slot="..."` attribute: it has to be a direct
+    /// child of a component, or somewhere inside a custom element.
+    fn can_fill_slot(&self) -> bool {
+        let parent_is_component = matches!(
+            self.open_nodes.last(),
+            Some(OpenNode::Element(parent)) if is_component(&parent.start_tag.tag_name.text)
+        );
+        parent_is_component
+            || self.open_nodes.iter().any(|open_node| {
+                matches!(
+                    open_node,
+                    OpenNode::Element(element) if element.start_tag.tag_name.text.contains('-')
+                )
+            })
+    }
+
+    fn finish_block(&mut self, block: OpenBlock, close: Option<Mustache>) {
+        let OpenBlock {
+            kind,
+            open,
+            children,
+            mut branches,
+            ..
+        } = block;
+
+        for branch in &mut branches {
+            if let Some(child) = branch.children.last() {
+                branch.span = branch.span.with_hi(child.span_hi());
+            }
+        }
+        let hi = match (&close, branches.last(), children.last()) {
+            (Some(close), _, _) => close.span_hi(),
+            (None, Some(branch), _) => branch.span_hi(),
+            (None, None, Some(child)) => child.span_hi(),
+            (None, None, None) => open.span_hi(),
+        };
+        if close.is_none() {
+            self.error_with_span(ParseErrorKind::BlockNotClosed, open.mustache_item.span());
+        }
+        for fragment in std::iter::once(&children).chain(branches.iter().map(|b| &b.children)) {
+            if let [Node::Text(text)] = fragment.as_slice() {
+                if text.text.trim().is_empty() {
+                    self.diagnostic(
+                        Diagnostic::warning(ParseErrorKind::EmptyBlock, text.span)
+                            .with_related(open.span, "block opened here")
+                            .with_tag(DiagnosticTag::Unnecessary),
+                    );
+                }
+            }
+        }
+
+        let span = open.span.with_hi(hi);
+        let node: Node = match kind {
+            BlockKind::If => IfBlock {
+                open,
+                children,
+                branches,
+                close,
+                span,
+            }
+            .into(),
+            BlockKind::Each => EachBlock {
+                open,
+                children,
+                branches,
+                close,
+                span,
+            }
+            .into(),
+            BlockKind::Await => AwaitBlock {
+                open,
+                children,
+                branches,
+                close,
+                span,
+            }
+            .into(),
+            // Key and snippet blocks don't have any continuations, so there are never any branches
+            BlockKind::Key => KeyBlock {
+                open,
+                children,
+                close,
+                span,
+            }
+            .into(),
+            BlockKind::Snippet => SnippetBlock {
+                open,
+                children,
+                close,
+                span,
+            }
+            .into(),
+        };
+        self.push_node(node);
     }
 
     pub(crate) fn eat(&mut self) -> Option<char> {
@@ -119,12 +829,57 @@ impl<'a> Parser<'a> {
         })
     }
 
-    pub(crate) fn require_whitespace(&mut self, error: ParseErrorKind) -> Option<WhitespaceToken> {
-        let whitespace = self.allow_whitespace();
-        if whitespace.is_none() {
-            self.error(error)
+    /// Eats whitespace that has to be there. If it isn't, `error` is reported and an empty token
+    /// is returned in its place.
+    pub(crate) fn require_whitespace(&mut self, error: ParseErrorKind) -> WhitespaceToken {
+        self.allow_whitespace().unwrap_or_else(|| {
+            let position = self.position();
+            self.error_with_fix(error, Fix::insert("Insert a space", position, " "));
+            WhitespaceToken {
+                span: new_span(position, position),
+            }
+        })
+    }
+
+    /// Eats everything up to the `}` that closes the mustache the parser is in, skipping over
+    /// nested braces and strings. Used to recover from JavaScript that couldn't be parsed.
+    pub(crate) fn eat_to_mustache_close(&mut self) -> Span {
+        let start = self.position();
+        let end = self.find_mustache_close();
+        self.eat_to(end);
+        new_span(start, end)
+    }
+
+    /// The position of the `}` that closes the mustache the parser is in, or the end of the source
+    /// if there isn't one. Nested braces and strings are skipped over.
+    pub(crate) fn find_mustache_close(&self) -> usize {
+        let start = self.position();
+        let rest = &self.text()[start..];
+        let mut end = rest.len();
+        let mut depth = 0;
+        let mut quote = None;
+        let mut chars = rest.char_indices();
+        while let Some((index, c)) = chars.next() {
+            match (quote, c) {
+                (Some(_), '\\') => {
+                    chars.next();
+                }
+                // Strings can't span lines, so an unclosed quote doesn't swallow the rest of the
+                // component.
+                (Some(_), '\n') => quote = None,
+                (Some(quote_char), c) if c == quote_char => quote = None,
+                (Some(_), _) => {}
+                (None, '"' | '\'') => quote = Some(c),
+                (None, '{') => depth += 1,
+                (None, '}') if depth == 0 => {
+                    end = index;
+                    break;
+                }
+                (None, '}') => depth -= 1,
+                (None, _) => {}
+            }
         }
-        whitespace
+        start + end
     }
 
     pub(crate) fn eat_until<P>(&mut self, pred: P) -> Span
@@ -136,7 +891,11 @@ impl<'a> Parser<'a> {
     }
 
     pub(crate) fn eat_until_chars(&mut self, s: &str) -> Span {
-        let (start, end) = self.muncher.eat_range_of(s);
+        let start = self.position();
+        let end = self.text()[start..]
+            .find(s)
+            .map_or(self.text().len(), |index| start + index);
+        self.eat_to(end);
         new_span(start, end)
     }
 
@@ -154,12 +913,101 @@ impl<'a> Parser<'a> {
 
     pub(crate) fn error(&mut self, kind: ParseErrorKind) {
         let pos = self.muncher.position();
-        self.error_with_span(kind, new_span(pos - 1, pos));
+        self.error_with_span(kind, new_span(pos.saturating_sub(1), pos));
     }
 
     pub(crate) fn error_with_span(&mut self, kind: ParseErrorKind, span: Span) {
-        self.error_reporter
-            .report_parse_error(ParseError { kind, span });
+        self.report_error(ParseError::new(kind, span));
+    }
+
+    /// Reports an error at the same span as [`error`](Self::error), with a fix for it.
+    pub(crate) fn error_with_fix(&mut self, kind: ParseErrorKind, fix: Fix) {
+        let pos = self.muncher.position();
+        self.report_error(
+            ParseError::new(kind, new_span(pos.saturating_sub(1), pos)).with_fix(fix),
+        );
+    }
+
+    pub(crate) fn report_error(&mut self, error: ParseError) {
+        self.error_reporter.report_parse_error(error);
+    }
+
+    /// Reports a diagnostic that isn't necessarily an error, like a warning. Warnings and hints
+    /// are left out if a `svelte-ignore` comment ignores them.
+    pub(crate) fn diagnostic(&mut self, diagnostic: Diagnostic) {
+        if diagnostic.severity != Severity::Error && self.is_ignored(diagnostic.kind.code()) {
+            return;
+        }
+        self.error_reporter.report_diagnostic(diagnostic);
+    }
+
+    /// Whether `code` is ignored by `svelte-ignore` comments before the node being parsed, or
+    /// before any of the elements and blocks it's inside.
+    fn is_ignored(&self, code: &str) -> bool {
+        let runes = self
+            .options
+            .as_ref()
+            .and_then(|options| options.runes)
+            .unwrap_or_default();
+        std::iter::once(self.nodes.as_slice())
+            .chain(self.open_nodes.iter().map(OpenNode::children))
+            .any(|siblings| {
+                ignored_codes(siblings, runes)
+                    .iter()
+                    .any(|ignored| ignored == code)
+            })
+    }
+
+    /// Runs `parse` with a JavaScript parser over `text`, which starts at `span.lo` in the source,
+    /// and reports the errors swc recovered from. `text` can have code added after the end of
+    /// `span` to make it parse, in which case errors in that code are moved to the end of `span`.
+    ///
+    /// Some invalid input makes swc fail a debug assertion, like `<!--` followed by a regular
+    /// expression, so a panic is returned as an error covering the whole of `span` instead, without
+    /// being printed by the panic hook. This only works when panics unwind; with `panic = "abort"`
+    /// the process is still aborted.
+    pub(crate) fn parse_js<'t, T>(
+        &mut self,
+        text: &'t str,
+        span: Span,
+        parse: impl FnOnce(&mut swc_ecma_parser::Parser<Lexer<'t>>) -> PResult<T>,
+    ) -> Result<T, ParseError> {
+        let syntax = self.syntax();
+        let text_hi = span.lo + BytePos(text.len() as u32);
+        silence_js_parser_panics();
+        PARSING_JS.with(|parsing| parsing.set(true));
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            let mut js_parser = swc_ecma_parser::Parser::new_from(Lexer::new(
+                syntax,
+                EsVersion::EsNext,
+                StringInput::new(text, span.lo, text_hi),
+                None,
+            ));
+            let result = parse(&mut js_parser);
+            (result, js_parser.take_errors())
+        }));
+        PARSING_JS.with(|parsing| parsing.set(false));
+
+        let clamp = |error: swc_ecma_parser::error::Error| {
+            let mut error = ParseError::from(error);
+            error.span = error
+                .span
+                .with_lo(error.span.lo.min(span.hi))
+                .with_hi(error.span.hi.min(span.hi));
+            error
+        };
+        match result {
+            Ok((result, errors)) => {
+                for error in errors {
+                    self.report_error(clamp(error));
+                }
+                result.map_err(clamp)
+            }
+            Err(..) => Err(ParseError::new(
+                ParseErrorKind::JavaScriptParserFailed,
+                span,
+            )),
+        }
     }
 
     pub(crate) fn span_from(&self, start: usize) -> Span {
@@ -167,6 +1015,114 @@ impl<'a> Parser<'a> {
     }
 }
 
+/// Whether `node` is one of the special elements that can only be used once, at the top level of
+/// a component.
+fn is_svelte_meta_tag(node: &Node) -> bool {
+    matches!(
+        node,
+        Node::SvelteWindow(..)
+            | Node::SvelteBody(..)
+            | Node::SvelteDocument(..)
+            | Node::SvelteHead(..)
+            | Node::SvelteOptionsElement(..)
+    )
+}
+
+/// Whether the tag is a component rather than an element: either capitalised, accessed through an
+/// object like `<ui.Button>`, or one of svelte's special component tags.
+fn is_component(tag_name: &str) -> bool {
+    tag_name.starts_with(|c: char| c.is_ascii_uppercase())
+        || tag_name.contains('.')
+        || matches!(tag_name, "svelte:self" | "svelte:component")
+}
+
+/// Whether `tag_name` is a plain HTML element, rather than a component, `<slot>` or `svelte:*`
+/// element.
+fn is_regular_element(tag_name: &str) -> bool {
+    !is_component(tag_name) && !tag_name.starts_with("svelte:") && tag_name != "slot"
+}
+
+/// The start tag of an element-like node, which can have a `slot="..."` attribute.
+fn node_start_tag(node: &Node) -> Option<&StartTag> {
+    match node {
+        Node::Element(element) => Some(&element.start_tag),
+        Node::Slot(slot) => Some(&slot.start_tag),
+        Node::SvelteSelf(element) => Some(&element.start_tag),
+        Node::SvelteComponent(element) => Some(&element.start_tag),
+        Node::SvelteElement(element) => Some(&element.start_tag),
+        Node::SvelteFragment(element) => Some(&element.start_tag),
+        _ => None,
+    }
+}
+
+thread_local! {
+    /// Whether swc is running on this thread, so a panic from it is caught by `Parser::parse_js`.
+    static PARSING_JS: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Wraps the panic hook so that it isn't called for panics `Parser::parse_js` is going to catch,
+/// which would otherwise print a message and backtrace from library code.
+fn silence_js_parser_panics() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !PARSING_JS.with(Cell::get) {
+                hook(info);
+            }
+        }));
+    });
+}
+
+/// Looks for a `<script lang="ts">` anywhere in the source, so that template expressions that come
+/// before the script are parsed as TypeScript too. The attributes are split up the same way as
+/// when the start tag is parsed, so only an attribute named `lang` counts.
+fn has_typescript_script(source: &str) -> bool {
+    source.match_indices("<script").any(|(index, tag)| {
+        let mut rest = &source[index + tag.len()..];
+        if !rest.starts_with(|c: char| c.is_ascii_whitespace()) {
+            return false;
+        }
+        loop {
+            rest = rest.trim_start_matches(|c: char| c.is_ascii_whitespace());
+            if rest.is_empty() || rest.starts_with('>') || rest.starts_with("/>") {
+                return false;
+            }
+
+            let name_len = rest.find(is_attribute_name_end).unwrap_or(rest.len());
+            // Skip a stray character like a lone `/` or `=` that can't start a name
+            let name_len = name_len.max(rest.chars().next().map_or(0, char::len_utf8));
+            let name = &rest[..name_len];
+            rest = rest[name_len..].trim_start_matches(|c: char| c.is_ascii_whitespace());
+
+            let mut value = None;
+            if let Some(after_equals) = rest.strip_prefix('=') {
+                let after_equals =
+                    after_equals.trim_start_matches(|c: char| c.is_ascii_whitespace());
+                let (text, after) = match after_equals.chars().next() {
+                    Some(quote @ ('"' | '\'')) => {
+                        let quoted = &after_equals[1..];
+                        let len = quoted.find(quote).unwrap_or(quoted.len());
+                        (&quoted[..len], quoted.get(len + 1..).unwrap_or_default())
+                    }
+                    _ => {
+                        let len = after_equals
+                            .find(is_unquoted_value_end)
+                            .unwrap_or(after_equals.len());
+                        after_equals.split_at(len)
+                    }
+                };
+                value = Some(text);
+                rest = after;
+            }
+
+            if name == "lang" {
+                return matches!(value, Some("ts" | "typescript"));
+            }
+        }
+    })
+}
+
 pub fn new_span(start: usize, end: usize) -> Span {
     Span::new(
         BytePos(start as u32),
@@ -0,0 +1,272 @@
+//! Renders diagnostics as annotated snippets of the source, for printing in a terminal or logs.
+
+use crate::error::{CollectingErrorReporter, Diagnostic, ParseError, Severity};
+use std::fmt::Write;
+use swc_common::Span;
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const RED: &str = "\x1b[1;31m";
+const YELLOW: &str = "\x1b[1;33m";
+const BLUE: &str = "\x1b[1;34m";
+const CYAN: &str = "\x1b[1;36m";
+
+/// Renders errors in the style of rustc:
+///
+/// ```text
+/// error[expected_token]: Expected token }
+///  --> App.svelte:1:5
+///   |
+/// 1 | <p>{a
+///   |     ^ expected `}`
+///   |
+///   = help: add a `}` to close the mustache
+/// ```
+pub struct Renderer<'a> {
+    source: &'a str,
+    file_name: Option<&'a str>,
+    colors: bool,
+    /// Byte offset of the start of each line.
+    line_starts: Vec<usize>,
+}
+
+impl<'a> Renderer<'a> {
+    /// Creates a renderer for errors from parsing `source`, which renders plain text.
+    pub fn new(source: &'a str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(source.match_indices('\n').map(|(index, _)| index + 1))
+            .collect();
+        Self {
+            source,
+            file_name: None,
+            colors: false,
+            line_starts,
+        }
+    }
+
+    /// Name of the file to show in front of the line and column.
+    pub fn with_file_name(mut self, file_name: &'a str) -> Self {
+        self.file_name = Some(file_name);
+        self
+    }
+
+    /// Colour the output with ANSI escape codes (or not).
+    pub fn with_colors(mut self, colors: bool) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    /// Renders all of the diagnostics collected by `error_reporter`, including warnings and hints,
+    /// separated by blank lines.
+    pub fn render(&self, error_reporter: &CollectingErrorReporter) -> String {
+        error_reporter
+            .diagnostics()
+            .iter()
+            .map(|diagnostic| self.render_diagnostic(diagnostic))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    pub fn render_error(&self, error: &ParseError) -> String {
+        self.render_diagnostic(&error.clone().into())
+    }
+
+    pub fn render_diagnostic(&self, diagnostic: &Diagnostic) -> String {
+        let (severity, color) = match diagnostic.severity {
+            Severity::Error => ("error", RED),
+            Severity::Warning => ("warning", YELLOW),
+            Severity::Hint => ("hint", CYAN),
+        };
+        let primary = self.lines(diagnostic.span);
+        let related: Vec<_> = diagnostic
+            .related
+            .iter()
+            .map(|related| (self.lines(related.span), related.message.as_str()))
+            .collect();
+        let last_line = related
+            .iter()
+            .map(|(lines, _)| lines.end.0)
+            .fold(primary.end.0, usize::max);
+        let gutter_width = (last_line + 1).to_string().len();
+        let gutter = " ".repeat(gutter_width);
+
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "{}{severity}[{}]{}: {}{}{}",
+            self.color(color),
+            diagnostic.kind.code(),
+            self.color(RESET),
+            self.color(BOLD),
+            diagnostic.kind,
+            self.color(RESET)
+        );
+        self.write_location(&mut out, &gutter, "-->", &primary);
+        self.write_gutter(&mut out, &gutter, "");
+        self.write_snippet(
+            &mut out,
+            gutter_width,
+            &primary,
+            ('^', color),
+            diagnostic.kind.label(),
+        );
+
+        for (lines, message) in &related {
+            self.write_gutter(&mut out, &gutter, "");
+            self.write_location(&mut out, &gutter, ":::", lines);
+            self.write_gutter(&mut out, &gutter, "");
+            self.write_snippet(&mut out, gutter_width, lines, ('-', BLUE), Some(message));
+        }
+
+        if let Some(help) = diagnostic.kind.help() {
+            self.write_gutter(&mut out, &gutter, "");
+            let _ = writeln!(
+                out,
+                "{gutter} {}={} {}help{}: {help}",
+                self.color(BLUE),
+                self.color(RESET),
+                self.color(CYAN),
+                self.color(RESET)
+            );
+        }
+
+        out
+    }
+
+    /// Zero based lines and columns of the start and end of `span`.
+    fn lines(&self, span: Span) -> Lines {
+        let lo = self.clamp(span.lo.0 as usize);
+        let hi = self.clamp(span.hi.0 as usize).max(lo);
+        let start = self.line_column(lo);
+        let mut end = self.line_column(hi);
+        // A span ending with a newline finishes at the end of that line, not the start of the next
+        if end.1 == 0 && end.0 > start.0 {
+            end.0 -= 1;
+            end.1 = self.line_text(end.0).chars().count();
+        }
+        Lines { start, end }
+    }
+
+    fn write_location(&self, out: &mut String, gutter: &str, arrow: &str, lines: &Lines) {
+        let _ = writeln!(
+            out,
+            "{gutter}{}{arrow}{} {}{}:{}",
+            self.color(BLUE),
+            self.color(RESET),
+            self.file_name
+                .map(|name| format!("{name}:"))
+                .unwrap_or_default(),
+            lines.start.0 + 1,
+            lines.start.1 + 1
+        );
+    }
+
+    /// Writes the lines of `lines` with markers under the span and `label` after the last marker.
+    fn write_snippet(
+        &self,
+        out: &mut String,
+        gutter_width: usize,
+        lines: &Lines,
+        (marker, color): (char, &'static str),
+        label: Option<&str>,
+    ) {
+        let gutter = " ".repeat(gutter_width);
+        let (start_line, start_column) = lines.start;
+        let (end_line, end_column) = lines.end;
+        for line in start_line..=end_line {
+            // Only show the first and last lines of long spans
+            if line > start_line + 1 && line < end_line {
+                if line == start_line + 2 {
+                    let _ = writeln!(out, "{}...{}", self.color(BLUE), self.color(RESET));
+                }
+                continue;
+            }
+
+            let text = self.line_text(line);
+            let from = if line == start_line { start_column } else { 0 };
+            let to = if line == end_line {
+                end_column
+            } else {
+                text.chars().count()
+            };
+            let number = (line + 1).to_string();
+            let _ = writeln!(
+                out,
+                "{}{number:>gutter_width$} |{} {text}",
+                self.color(BLUE),
+                self.color(RESET)
+            );
+
+            // Keep tabs in the padding so the markers line up with the text above them
+            let padding: String = text
+                .chars()
+                .take(from)
+                .map(|c| if c == '\t' { '\t' } else { ' ' })
+                .collect();
+            let markers = marker.to_string().repeat(to.saturating_sub(from).max(1));
+            let label = match label {
+                Some(label) if line == end_line => format!(" {label}"),
+                _ => String::new(),
+            };
+            self.write_gutter(
+                out,
+                &gutter,
+                &format!(
+                    "{padding}{}{markers}{label}{}",
+                    self.color(color),
+                    self.color(RESET)
+                ),
+            );
+        }
+    }
+
+    fn write_gutter(&self, out: &mut String, gutter: &str, content: &str) {
+        let _ = writeln!(
+            out,
+            "{gutter} {}|{}{}{content}",
+            self.color(BLUE),
+            self.color(RESET),
+            if content.is_empty() { "" } else { " " }
+        );
+    }
+
+    fn color(&self, code: &'static str) -> &'static str {
+        if self.colors {
+            code
+        } else {
+            ""
+        }
+    }
+
+    /// Moves an offset back to the nearest char boundary in the source, so that out of range or
+    /// mid-character spans can still be rendered.
+    fn clamp(&self, offset: usize) -> usize {
+        let mut offset = offset.min(self.source.len());
+        while !self.source.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        offset
+    }
+
+    /// Zero based line and column (in chars) of a byte offset.
+    fn line_column(&self, offset: usize) -> (usize, usize) {
+        let line = self.line_starts.partition_point(|start| *start <= offset) - 1;
+        let column = self.source[self.line_starts[line]..offset].chars().count();
+        (line, column)
+    }
+
+    fn line_text(&self, line: usize) -> &'a str {
+        let start = self.line_starts[line];
+        let end = self
+            .line_starts
+            .get(line + 1)
+            .map_or(self.source.len(), |next| next - 1);
+        self.source[start..end].trim_end_matches('\r')
+    }
+}
+
+/// Start and end of a span as zero based lines and columns.
+struct Lines {
+    start: (usize, usize),
+    end: (usize, usize),
+}
@@ -1,16 +1,27 @@
-use self::{fragment::FragmentState, mustache::MustacheState, tag::TagState, text::TextState};
+use self::{
+    fragment::FragmentState, mustache::MustacheState, raw_text::RawTextState, script::ScriptState,
+    style::StyleState, tag::TagState, text::TextState,
+};
 use crate::parser::Parser;
 use derive_more::From;
 
 mod fragment;
 mod mustache;
+mod raw_text;
+mod script;
+mod style;
 mod tag;
 mod text;
 
+pub(crate) use tag::{is_attribute_name_end, is_unquoted_value_end};
+
 #[derive(Debug, From)]
 pub enum State {
     Fragment(FragmentState),
     Mustache(MustacheState),
+    RawText(RawTextState),
+    Script(ScriptState),
+    Style(StyleState),
     Tag(TagState),
     Text(TextState),
     Eof,
@@ -41,6 +52,9 @@ impl StateTransition for State {
         match self {
             State::Fragment(s) => s.next_state(parser),
             State::Mustache(s) => s.next_state(parser),
+            State::RawText(s) => s.next_state(parser),
+            State::Script(s) => s.next_state(parser),
+            State::Style(s) => s.next_state(parser),
             State::Tag(s) => s.next_state(parser),
             State::Text(s) => s.next_state(parser),
             State::Eof => State::Eof,
@@ -6,9 +6,15 @@ pub struct FragmentState;
 
 impl StateTransition for FragmentState {
     fn next_state(self, parser: &mut Parser<'_>) -> State {
+        // Inside `<textarea>` and `<title>` the only tag is the element's own end tag.
+        let is_tag = match parser.escapable_raw_text_end_tag() {
+            Some(end_tag) => parser.peek_chars(&end_tag),
+            None => true,
+        };
+
         match parser.peek() {
-            Some('<') => TagState.into(),
-            Some('{') => MustacheState::default().into(),
+            Some('<') if is_tag => TagState.into(),
+            Some('{') => MustacheState.into(),
             Some(_) => TextState.into(),
             None => State::Eof,
         }
@@ -1,19 +1,23 @@
 use super::{fragment::FragmentState, State, StateTransition};
 use crate::{
-    error::ParseErrorKind,
-    parser::Parser,
+    error::{ParseError, ParseErrorKind},
+    fix::Fix,
+    parser::{new_span, BlockKind, BranchKind, Parser},
     syntax_nodes::{
-        BlockClose, BlockOpen, ConstTag, DebugTag, EachAs, EachBlockOpen, EachIndex, EachKey,
-        IfBlockOpen, InvalidSyntax, KeyBlockOpen, Mustache, MustacheItem, RawMustacheTag,
+        AwaitBlockOpen, AwaitCatch, AwaitShorthand, AwaitThen, BlockClose, BlockContinuation,
+        BlockOpen, CatchBlock, ConstTag, DebugTag, EachAs, EachBlockOpen, EachIndex, EachKey,
+        ElseBlock, ElseIfBlock, IfBlockOpen, InvalidSyntax, KeyBlockOpen, Mustache, MustacheItem,
+        RawMustacheTag, RenderTag, SnippetBlockOpen, ThenBlock,
     },
     tokens::{
-        ConstTagToken, DebugTagToken, HtmlTagToken, IfOpenToken, KeyOpenToken, MustacheCloseToken,
-        MustacheOpenToken,
+        AwaitOpenToken, CatchToken, ConstTagToken, DebugTagToken, HtmlTagToken, IfOpenToken,
+        IfToken, KeyOpenToken, MustacheCloseToken, MustacheOpenToken, RenderTagToken,
+        SnippetOpenToken, ThenToken, WhitespaceToken,
     },
 };
-use swc_common::{source_map::BytePos, Span, Spanned};
-use swc_ecma_ast::{AssignOp, EsVersion, Expr, Ident, Pat};
-use swc_ecma_parser::{lexer::Lexer, EsConfig, StringInput, Syntax};
+use swc_common::{util::take::Take, Span, Spanned};
+use swc_ecma_ast::{AssignOp, Expr, Ident, Invalid, OptChainBase, Pat, TsTypeAnn};
+use swc_ecma_parser::{lexer::Lexer, PResult};
 
 #[derive(Debug, Default)]
 pub struct MustacheState;
@@ -32,12 +36,18 @@ impl StateTransition for MustacheState {
             self.parse_block_close_tag(parser, span).into()
         } else if let Some(span) = parser.eat_char('#') {
             self.parse_block_open_tag(parser, span).into()
+        } else if let Some(span) = parser.eat_char(':') {
+            self.parse_block_continuation(parser, span).into()
         } else if let Some(span) = parser.eat_chars("@html") {
             self.parse_raw_mustache_tag(parser, HtmlTagToken { span })
         } else if let Some(span) = parser.eat_chars("@debug") {
             self.parse_debug_tag(parser, DebugTagToken { span })
         } else if let Some(span) = parser.eat_chars("@const") {
             self.parse_const_tag(parser, ConstTagToken { span })
+        } else if let Some(span) = parser.eat_chars("@render") {
+            self.parse_render_tag(parser, RenderTagToken { span })
+        } else if let Some(span) = parser.eat_chars("...") {
+            self.parse_misplaced_spread(parser, span)
         } else {
             self.parse_mustache_tag(parser)
         };
@@ -47,71 +57,147 @@ impl StateTransition for MustacheState {
         let mustache_close = match parser.eat_char('}') {
             Some(span) => Some(MustacheCloseToken { span }),
             None => {
-                parser.error(ParseErrorKind::MustacheNotClosed);
+                let position = parser.position();
+                parser.error_with_fix(
+                    ParseErrorKind::MustacheNotClosed,
+                    Fix::insert("Insert missing `}`", position, "}"),
+                );
                 None
             }
         };
 
-        parser.push_node(Mustache {
+        let mustache = Mustache {
             mustache_open,
             leading_whitespace,
             mustache_item,
             trailing_whitespace,
             mustache_close,
             span: parser.span_from(start),
-        });
+        };
+        validate_placement(parser, &mustache.mustache_item);
+        match block_tag(&mustache.mustache_item) {
+            Some(BlockTag::Open(kind, branch)) => parser.open_block(kind, branch, mustache),
+            Some(BlockTag::Continuation(kind)) => parser.continue_block(kind, mustache),
+            Some(BlockTag::Close(kind)) => parser.close_block(kind, mustache),
+            None => parser.push_node(mustache),
+        }
         FragmentState.into()
     }
 }
 
 impl MustacheState {
-    fn parse_js_expression(&self, parser: &mut Parser<'_>) -> Box<Expr> {
+    /// Runs `parse` with a JavaScript parser starting at the current position and running to the
+    /// end of the source, reporting any errors swc recovered from along the way.
+    fn parse_js<'a, T>(
+        &self,
+        parser: &mut Parser<'a>,
+        parse: impl FnOnce(&mut swc_ecma_parser::Parser<Lexer<'a>>) -> PResult<T>,
+    ) -> Result<T, ParseError> {
         let source = parser.text();
-        let mut ecma_parser = swc_ecma_parser::Parser::new_from(Lexer::new(
-            Syntax::Es(EsConfig::default()),
-            EsVersion::EsNext,
-            StringInput::new(
-                &source[parser.position()..],
-                BytePos(parser.position() as u32),
-                BytePos(source.len() as u32),
-            ),
-            None,
-        ));
+        let position = parser.position();
+        parser.parse_js(&source[position..], new_span(position, source.len()), parse)
+    }
 
-        let expression = ecma_parser.parse_expr().unwrap();
+    /// Parses a JavaScript expression. If it can't be parsed, the error is reported and the rest
+    /// of the mustache is skipped and kept as an invalid expression.
+    pub(super) fn parse_js_expression(&self, parser: &mut Parser<'_>) -> Box<Expr> {
+        let expression = match self.parse_js(parser, |js_parser| js_parser.parse_expr()) {
+            Ok(expression) => expression,
+            Err(error) => return invalid_expression(parser, error),
+        };
         parser.eat_to(expression.span_hi().0 as usize);
         expression
     }
 
-    fn parse_js_pattern(&self, parser: &mut Parser) -> Pat {
-        let source = parser.text();
-        let mut ecma_parser = swc_ecma_parser::Parser::new_from(Lexer::new(
-            Syntax::Es(EsConfig::default()),
-            EsVersion::EsNext,
-            StringInput::new(
-                &source[parser.position()..],
-                BytePos(parser.position() as u32),
-                BytePos(source.len() as u32),
-            ),
-            None,
-        ));
+    fn parse_each_expression(&self, parser: &mut Parser<'_>) -> Box<Expr> {
+        let mut expression = match self.parse_js(parser, |js_parser| js_parser.parse_expr()) {
+            Ok(expression) => expression,
+            Err(error) => return invalid_expression(parser, error),
+        };
+        // In TypeScript `items as item` is an expression by itself, so we need to take the
+        // expression out of the `as` and leave the rest to be parsed as the context
+        take_each_as(&mut expression);
+        parser.eat_to(expression.span_hi().0 as usize);
+        expression
+    }
 
-        let pat = ecma_parser.parse_pat().unwrap();
+    /// Parses a JavaScript pattern. If it can't be parsed, the error is reported and the rest of
+    /// the mustache is skipped and kept as an invalid pattern.
+    fn parse_js_pattern(&self, parser: &mut Parser) -> Pat {
+        let mut pat = match self.parse_js(parser, |js_parser| js_parser.parse_pat()) {
+            Ok(pat) => pat,
+            Err(error) => return invalid_pattern(parser, error),
+        };
         parser.eat_to(pat.span_hi().0 as usize);
+
+        // swc only parses type annotations as part of a declaration, so we have to add them to
+        // the pattern ourselves
+        if parser.is_typescript() && parser.peek_ignore_whitespace() == Some(&':') {
+            parser.allow_whitespace();
+            let colon_span = parser.eat_char(':').unwrap_or_default();
+            let type_ann = match self.parse_ts_type_annotation(parser, colon_span) {
+                Ok(type_ann) => Some(type_ann),
+                Err(error) => return invalid_pattern(parser, error),
+            };
+            match &mut pat {
+                Pat::Ident(pat) => pat.type_ann = type_ann,
+                Pat::Array(pat) => pat.type_ann = type_ann,
+                Pat::Object(pat) => pat.type_ann = type_ann,
+                _ => {}
+            }
+        }
         pat
     }
 
+    fn parse_ts_type_annotation(
+        &self,
+        parser: &mut Parser,
+        colon_span: Span,
+    ) -> Result<Box<TsTypeAnn>, ParseError> {
+        let type_ann = self.parse_js(parser, |js_parser| js_parser.parse_type())?;
+        parser.eat_to(type_ann.span_hi().0 as usize);
+
+        Ok(Box::new(TsTypeAnn {
+            span: colon_span.with_hi(type_ann.span_hi()),
+            type_ann,
+        }))
+    }
+
     fn parse_mustache_tag(self, parser: &mut Parser<'_>) -> MustacheItem {
-        self.parse_js_expression(parser).into()
+        let expression = self.parse_js_expression(parser);
+        match *expression {
+            Expr::Invalid(Invalid { span }) => InvalidSyntax {
+                text: parser.text_span(&span).into(),
+                span,
+            }
+            .into(),
+            _ => expression.into(),
+        }
+    }
+
+    /// Spreads are only valid as attributes, so in any other position we parse the expression to
+    /// skip over it, then report the whole thing as invalid.
+    fn parse_misplaced_spread(self, parser: &mut Parser<'_>, spread_span: Span) -> MustacheItem {
+        let expression = self.parse_js_expression(parser);
+        let span = spread_span.with_hi(expression.span_hi());
+        parser.error_with_span(ParseErrorKind::UnexpectedSpread, span);
+        InvalidSyntax {
+            text: parser.text_span(&span).into(),
+            span,
+        }
+        .into()
     }
 
     fn parse_const_tag(self, parser: &mut Parser<'_>, const_tag: ConstTagToken) -> MustacheItem {
-        let whitespace = parser
-            .require_whitespace(ParseErrorKind::MissingWhitespaceAfterConstTag)
-            .unwrap();
+        let whitespace = parser.require_whitespace(ParseErrorKind::MissingWhitespaceAfterConstTag);
         let expression = self.parse_js_expression(parser);
 
-        if !matches!(&*expression, Expr::Assign(expr) if expr.op == AssignOp::Assign) {
+        let is_valid = match &*expression {
+            Expr::Assign(expr) => expr.op == AssignOp::Assign,
+            Expr::Invalid(..) => true,
+            _ => false,
+        };
+        if !is_valid {
             parser.error_with_span(ParseErrorKind::InvalidConstArgs, expression.span());
         }
 
@@ -130,7 +216,7 @@ impl MustacheState {
 
         let is_debug_all = matches!(parser.peek(), Some('}'));
         if !is_debug_all && whitespace.is_none() {
-            todo!()
+            parser.error(ParseErrorKind::MissingWhitespaceAfterDebugTag);
         }
 
         if is_debug_all {
@@ -157,7 +243,7 @@ impl MustacheState {
                 vec![expression]
             };
             for expression in &identifiers {
-                if !matches!(&**expression, Expr::Ident(..)) {
+                if !matches!(&**expression, Expr::Ident(..) | Expr::Invalid(..)) {
                     parser.error_with_span(ParseErrorKind::InvalidDebugArgs, expression.span());
                 }
             }
@@ -177,9 +263,7 @@ impl MustacheState {
         parser: &mut Parser<'_>,
         html_tag: HtmlTagToken,
     ) -> MustacheItem {
-        let whitespace = parser
-            .require_whitespace(ParseErrorKind::MissingWhitespaceAfterHtmlTag)
-            .unwrap();
+        let whitespace = parser.require_whitespace(ParseErrorKind::MissingWhitespaceAfterHtmlTag);
         let expression = self.parse_js_expression(parser);
 
         let span = html_tag.span().with_hi(expression.span_hi());
@@ -192,12 +276,34 @@ impl MustacheState {
         .into()
     }
 
+    fn parse_render_tag(self, parser: &mut Parser<'_>, render_tag: RenderTagToken) -> MustacheItem {
+        let whitespace = parser.require_whitespace(ParseErrorKind::MissingWhitespaceAfterRenderTag);
+        let expression = self.parse_js_expression(parser);
+
+        let is_call = match &*expression {
+            Expr::Call(..) | Expr::Invalid(..) => true,
+            Expr::OptChain(expr) => matches!(&*expr.base, OptChainBase::Call(..)),
+            _ => false,
+        };
+        if !is_call {
+            parser.error_with_span(ParseErrorKind::InvalidRenderExpression, expression.span());
+        }
+
+        let span = render_tag.span().with_hi(expression.span_hi());
+        RenderTag {
+            render_tag,
+            whitespace,
+            expression,
+            span,
+        }
+        .into()
+    }
+
     fn parse_block_open_tag(self, parser: &mut Parser<'_>, hash_span: Span) -> BlockOpen {
         if let Some(span) = parser.eat_chars("if") {
             let span = span.with_lo(hash_span.lo());
-            let whitespace = parser
-                .require_whitespace(ParseErrorKind::MissingWhitespaceAfterBlockOpen)
-                .unwrap();
+            let whitespace =
+                parser.require_whitespace(ParseErrorKind::MissingWhitespaceAfterBlockOpen);
             let expression = self.parse_js_expression(parser);
             IfBlockOpen {
                 if_open: IfOpenToken { span },
@@ -209,17 +315,34 @@ impl MustacheState {
         } else if let Some(each_span) = parser.eat_chars("each") {
             let start = hash_span.lo().0 as usize;
             let each_span = each_span.with_lo(hash_span.lo());
-            let whitespace = parser
-                .require_whitespace(ParseErrorKind::MissingWhitespaceAfterBlockOpen)
-                .unwrap();
-            let expression = self.parse_js_expression(parser);
-            let as_leading_ws = parser
-                .require_whitespace(ParseErrorKind::MissingWhitespaceBeforeAs)
-                .unwrap();
-            let as_ = parser.eat_chars("as").unwrap().into();
-            let as_trailing_ws = parser
-                .require_whitespace(ParseErrorKind::MissingWhitespaceAfterAs)
-                .unwrap();
+            let whitespace =
+                parser.require_whitespace(ParseErrorKind::MissingWhitespaceAfterBlockOpen);
+            let expression = self.parse_each_expression(parser);
+            let Some(as_) = self.parse_each_as(parser) else {
+                // Keep the block open with placeholders for the missing parts, so that its
+                // children and `{/each}` still end up in the right place.
+                let skipped = parser.eat_to_mustache_close();
+                if !expression.is_invalid() {
+                    parser.error_with_span(ParseErrorKind::ExpectedEachAs, skipped);
+                }
+                let missing = new_span(skipped.lo.0 as usize, skipped.lo.0 as usize);
+                return EachBlockOpen {
+                    each_open: each_span.into(),
+                    whitespace,
+                    expression,
+                    as_: EachAs {
+                        leading_ws: missing.into(),
+                        as_: missing.into(),
+                        trailing_ws: missing.into(),
+                        span: missing,
+                    },
+                    context: Pat::Invalid(Invalid { span: skipped }),
+                    index: None,
+                    key: None,
+                    span: parser.span_from(start),
+                }
+                .into();
+            };
             let context = self.parse_js_pattern(parser);
 
             let (index, key) = match parser.peek_ignore_whitespace() {
@@ -232,25 +355,33 @@ impl MustacheState {
                 each_open: each_span.into(),
                 whitespace,
                 expression,
-                as_: EachAs {
-                    span: as_leading_ws.span().with_hi(as_trailing_ws.span_hi()),
-                    leading_ws: as_leading_ws,
-                    as_,
-                    trailing_ws: as_trailing_ws,
-                },
+                as_,
                 context,
                 index,
                 key,
                 span: parser.span_from(start),
             }
             .into()
-        } else if let Some(_span) = parser.eat_chars("await") {
-            todo!()
+        } else if let Some(span) = parser.eat_chars("await") {
+            let start = hash_span.lo().0 as usize;
+            let span = span.with_lo(hash_span.lo());
+            let whitespace =
+                parser.require_whitespace(ParseErrorKind::MissingWhitespaceAfterBlockOpen);
+            let expression = self.parse_js_expression(parser);
+            let await_shorthand = self.parse_await_shorthand(parser);
+
+            AwaitBlockOpen {
+                await_open: AwaitOpenToken { span },
+                whitespace,
+                expression,
+                await_shorthand,
+                span: parser.span_from(start),
+            }
+            .into()
         } else if let Some(span) = parser.eat_chars("key") {
             let span = span.with_lo(hash_span.lo());
-            let whitespace = parser
-                .require_whitespace(ParseErrorKind::MissingWhitespaceAfterBlockOpen)
-                .unwrap();
+            let whitespace =
+                parser.require_whitespace(ParseErrorKind::MissingWhitespaceAfterBlockOpen);
             let expression = self.parse_js_expression(parser);
             KeyBlockOpen {
                 key_open: KeyOpenToken { span },
@@ -259,6 +390,13 @@ impl MustacheState {
                 expression,
             }
             .into()
+        } else if let Some(span) = parser.eat_chars("snippet") {
+            self.parse_snippet_block_open(
+                parser,
+                SnippetOpenToken {
+                    span: span.with_lo(hash_span.lo()),
+                },
+            )
         } else {
             let span = parser
                 .eat_until(|c| *c == '}' || c.is_ascii_whitespace())
@@ -272,12 +410,268 @@ impl MustacheState {
         }
     }
 
+    /// Parses `{#snippet name(params)}`. The parameters are parsed as the parameters of an arrow
+    /// function, so they can have default values and type annotations.
+    fn parse_snippet_block_open(
+        &self,
+        parser: &mut Parser,
+        snippet_open: SnippetOpenToken,
+    ) -> BlockOpen {
+        let start = snippet_open.span.lo.0 as usize;
+        let whitespace = parser.require_whitespace(ParseErrorKind::MissingWhitespaceAfterBlockOpen);
+        if !parser.peek().is_some_and(|c| Ident::is_valid_start(*c)) {
+            let position = parser.position();
+            return self.invalid_snippet_block_open(
+                parser,
+                snippet_open,
+                whitespace,
+                Ident::new("".into(), new_span(position, position)),
+                None,
+                ParseErrorKind::ExpectedSnippetName,
+            );
+        }
+        let name = self.parse_identifier(parser);
+        let trailing_ws = parser.allow_whitespace();
+
+        let params_start = parser.position();
+        let Some(params_len) = snippet_params_len(&parser.text()[params_start..]) else {
+            return self.invalid_snippet_block_open(
+                parser,
+                snippet_open,
+                whitespace,
+                name,
+                trailing_ws,
+                ParseErrorKind::ExpectedSnippetParameters,
+            );
+        };
+        let params_end = params_start + params_len;
+        let params = self.parse_snippet_params(parser, params_start, params_end);
+        parser.eat_to(params_end);
+
+        SnippetBlockOpen {
+            snippet_open,
+            whitespace,
+            name,
+            trailing_ws,
+            paren_open: new_span(params_start, params_start + 1).into(),
+            params,
+            paren_close: new_span(params_end - 1, params_end).into(),
+            span: parser.span_from(start),
+        }
+        .into()
+    }
+
+    /// Parses the parenthesised parameters between `start` and `end` by parsing them with an arrow
+    /// function body added on, the same way the svelte parser does. JavaScript errors in them are
+    /// reported, and leave the snippet without parameters.
+    fn parse_snippet_params(&self, parser: &mut Parser, start: usize, end: usize) -> Vec<Pat> {
+        let source = format!("{} => {{}}", &parser.text()[start..end]);
+        let result = parser.parse_js(&source, new_span(start, end), |js_parser| {
+            js_parser.parse_expr()
+        });
+        match result.map(|expression| *expression) {
+            Ok(Expr::Arrow(arrow)) => arrow.params,
+            Ok(..) => {
+                parser.error_with_span(
+                    ParseErrorKind::ExpectedSnippetParameters,
+                    new_span(start, end),
+                );
+                Vec::new()
+            }
+            Err(error) => {
+                parser.report_error(error);
+                Vec::new()
+            }
+        }
+    }
+
+    /// Skips the rest of a snippet open that can't be parsed, reporting it as `kind`.
+    fn invalid_snippet_block_open(
+        &self,
+        parser: &mut Parser,
+        snippet_open: SnippetOpenToken,
+        whitespace: WhitespaceToken,
+        name: Ident,
+        trailing_ws: Option<WhitespaceToken>,
+        kind: ParseErrorKind,
+    ) -> BlockOpen {
+        let start = snippet_open.span.lo.0 as usize;
+        parser.eat_until(|c| *c == '}');
+        let span = parser.span_from(start);
+        parser.error_with_span(kind, span);
+
+        // The snippet is still opened, so that its `{/snippet}` doesn't cause another error
+        let end = new_span(span.hi.0 as usize, span.hi.0 as usize);
+        SnippetBlockOpen {
+            snippet_open,
+            whitespace,
+            name,
+            trailing_ws,
+            paren_open: end.into(),
+            params: Vec::new(),
+            paren_close: end.into(),
+            span,
+        }
+        .into()
+    }
+
+    /// Parses the ` as ` between the expression and the context of an each block.
+    fn parse_each_as(&self, parser: &mut Parser) -> Option<EachAs> {
+        if !parser.peek_keyword("as") {
+            return None;
+        }
+        let start = parser.position();
+        let leading_ws = parser.require_whitespace(ParseErrorKind::MissingWhitespaceBeforeAs);
+        let as_ = parser.eat_chars("as")?.into();
+        let trailing_ws = parser.require_whitespace(ParseErrorKind::MissingWhitespaceAfterAs);
+
+        Some(EachAs {
+            leading_ws,
+            as_,
+            trailing_ws,
+            span: parser.span_from(start),
+        })
+    }
+
+    /// Parses the `then value` or `catch error` in `{#await promise then value}`, which skips the
+    /// pending branch of the block.
+    fn parse_await_shorthand(&self, parser: &mut Parser) -> Option<AwaitShorthand> {
+        let start = parser.position();
+        if !parser.peek().is_some_and(char::is_ascii_whitespace) {
+            return None;
+        }
+
+        if parser.peek_keyword("then") {
+            let leading_ws = parser.allow_whitespace()?;
+            let then = ThenToken {
+                span: parser.eat_chars("then")?,
+            };
+            let (trailing_ws, value) = self.parse_optional_pattern(parser);
+            Some(
+                AwaitThen {
+                    leading_ws,
+                    then,
+                    trailing_ws,
+                    value,
+                    span: parser.span_from(start),
+                }
+                .into(),
+            )
+        } else if parser.peek_keyword("catch") {
+            let leading_ws = parser.allow_whitespace()?;
+            let catch = CatchToken {
+                span: parser.eat_chars("catch")?,
+            };
+            let (trailing_ws, error) = self.parse_optional_pattern(parser);
+            Some(
+                AwaitCatch {
+                    leading_ws,
+                    catch,
+                    trailing_ws,
+                    error,
+                    span: parser.span_from(start),
+                }
+                .into(),
+            )
+        } else {
+            None
+        }
+    }
+
+    /// Parses the pattern for the value of a `then` or `catch`, which can be left out if the value
+    /// isn't needed.
+    fn parse_optional_pattern(
+        &self,
+        parser: &mut Parser,
+    ) -> (Option<WhitespaceToken>, Option<Pat>) {
+        let whitespace = parser.allow_whitespace();
+        let pattern = match parser.peek() {
+            None | Some('}') => None,
+            Some(_) => Some(self.parse_js_pattern(parser)),
+        };
+        (whitespace, pattern)
+    }
+
+    fn parse_block_continuation(
+        self,
+        parser: &mut Parser<'_>,
+        colon_span: Span,
+    ) -> BlockContinuation {
+        let start = colon_span.lo().0 as usize;
+        let name_span = parser.peek_until(|c| *c == '}' || c.is_ascii_whitespace());
+        let span = colon_span.with_hi(name_span.hi);
+
+        let name = parser.text_span(&name_span);
+        parser.eat_to_span_hi(&name_span);
+
+        match name {
+            "else" => match self.parse_else_if(parser, span) {
+                Some(else_if) => else_if.into(),
+                None => ElseBlock {
+                    else_branch: span.into(),
+                    span,
+                }
+                .into(),
+            },
+            "then" => {
+                let (whitespace, value) = self.parse_optional_pattern(parser);
+                ThenBlock {
+                    then_branch: span.into(),
+                    whitespace,
+                    value,
+                    span: parser.span_from(start),
+                }
+                .into()
+            }
+            "catch" => {
+                let (whitespace, error) = self.parse_optional_pattern(parser);
+                CatchBlock {
+                    catch_branch: span.into(),
+                    whitespace,
+                    error,
+                    span: parser.span_from(start),
+                }
+                .into()
+            }
+            _ => {
+                parser.error_with_span(ParseErrorKind::UnknownBlockContinuation, span);
+                InvalidSyntax {
+                    text: parser.text_span(&span).into(),
+                    span,
+                }
+                .into()
+            }
+        }
+    }
+
+    /// Parses the rest of `{:else if condition}`, if the `{:else` is followed by `if`.
+    fn parse_else_if(&self, parser: &mut Parser, else_span: Span) -> Option<ElseIfBlock> {
+        if !parser.peek_keyword("if") {
+            return None;
+        }
+        let leading_ws = parser.allow_whitespace()?;
+        let if_ = IfToken {
+            span: parser.eat_chars("if")?,
+        };
+        let trailing_ws =
+            parser.require_whitespace(ParseErrorKind::MissingWhitespaceAfterBlockOpen);
+        let expression = self.parse_js_expression(parser);
+        Some(ElseIfBlock {
+            else_branch: else_span.into(),
+            leading_ws,
+            if_,
+            trailing_ws,
+            expression,
+            span: parser.span_from(else_span.lo.0 as usize),
+        })
+    }
+
     fn parse_each_index(&self, parser: &mut Parser) -> Option<EachIndex> {
         let start = parser.position();
         let trailing_ws = parser.allow_whitespace();
         let comma = parser.eat_char(',')?;
         let whitespace = parser.allow_whitespace();
-        if Ident::is_valid_start(*parser.peek().unwrap()) {
+        if parser.peek().is_some_and(|c| Ident::is_valid_start(*c)) {
             let identifier = self.parse_identifier(parser);
 
             Some(EachIndex {
@@ -301,7 +695,19 @@ impl MustacheState {
         let leading_ws = parser.allow_whitespace();
         let expression = self.parse_js_expression(parser);
         let trailing_ws = parser.allow_whitespace();
-        let paren_close = parser.eat_char(')').unwrap().into();
+        let paren_close = match parser.eat_char(')') {
+            Some(span) => span.into(),
+            None => {
+                let position = parser.position();
+                if !expression.is_invalid() {
+                    parser.error_with_fix(
+                        ParseErrorKind::EachKeyNotClosed,
+                        Fix::insert("Insert missing `)`", position, ")"),
+                    );
+                }
+                new_span(position, position).into()
+            }
+        };
 
         Some(EachKey {
             whitespace,
@@ -315,13 +721,8 @@ impl MustacheState {
     }
 
     fn parse_identifier(&self, parser: &mut Parser) -> Ident {
-        let start = parser.position();
-        let start_char = parser.eat().unwrap();
-        debug_assert!(Ident::is_valid_start(start_char));
-
-        let span = parser
-            .eat_until(|c| !Ident::is_valid_continue(*c))
-            .with_lo(BytePos(start as u32));
+        // Callers check that the identifier starts with a valid character first
+        let span = parser.eat_until(|c| !Ident::is_valid_continue(*c));
         Ident::new(parser.text_span(&span).into(), span)
     }
 
@@ -346,10 +747,22 @@ impl MustacheState {
                 parser.eat_to_span_hi(&close_name_span);
                 BlockClose::AwaitClose(slash_span.with_hi(close_name_span.hi).into())
             }
+            "snippet" => {
+                parser.eat_to_span_hi(&close_name_span);
+                BlockClose::SnippetClose(slash_span.with_hi(close_name_span.hi).into())
+            }
             _ => {
                 let span = close_name_span.with_lo(slash_span.lo);
                 parser.eat_to_span_hi(&close_name_span);
-                parser.error_with_span(ParseErrorKind::UnknownBlockClose, close_name_span);
+                let mut error = ParseError::new(ParseErrorKind::UnknownBlockClose, close_name_span);
+                if let Some(kind) = parser.innermost_block() {
+                    error = error.with_fix(Fix::replace(
+                        format!("Replace `{{/{close_name}}}` with `{{/{}}}`", kind.name()),
+                        close_name_span,
+                        kind.name(),
+                    ));
+                }
+                parser.report_error(error);
                 InvalidSyntax {
                     text: parser.text_span(&span).into(),
                     span,
@@ -359,3 +772,141 @@ impl MustacheState {
         }
     }
 }
+
+/// Reports `{@...}` tags that svelte doesn't allow where they are.
+fn validate_placement(parser: &mut Parser<'_>, mustache_item: &MustacheItem) {
+    let in_raw_text = parser.escapable_raw_text_end_tag().is_some();
+    let kind = match mustache_item {
+        MustacheItem::ConstTag(..) if !parser.can_contain_const_tag() => {
+            ParseErrorKind::InvalidConstTagPlacement
+        }
+        MustacheItem::DebugTag(..) if in_raw_text => ParseErrorKind::InvalidDebugTagPlacement,
+        MustacheItem::RawMustacheTag(..) if in_raw_text => ParseErrorKind::InvalidHtmlTagPlacement,
+        _ => return,
+    };
+    parser.error_with_span(kind, mustache_item.span());
+}
+
+/// How a mustache fits into the block it opens, continues or closes.
+enum BlockTag {
+    Open(BlockKind, Option<BranchKind>),
+    Continuation(BranchKind),
+    Close(BlockKind),
+}
+
+fn block_tag(mustache_item: &MustacheItem) -> Option<BlockTag> {
+    let tag = match mustache_item {
+        MustacheItem::BlockOpen(open) => match open {
+            BlockOpen::IfBlockOpen(..) => BlockTag::Open(BlockKind::If, None),
+            BlockOpen::EachBlockOpen(..) => BlockTag::Open(BlockKind::Each, None),
+            BlockOpen::AwaitBlockOpen(open) => BlockTag::Open(
+                BlockKind::Await,
+                open.await_shorthand
+                    .as_ref()
+                    .map(|shorthand| match shorthand {
+                        AwaitShorthand::AwaitThen(..) => BranchKind::Then,
+                        AwaitShorthand::AwaitCatch(..) => BranchKind::Catch,
+                    }),
+            ),
+            BlockOpen::KeyBlockOpen(..) => BlockTag::Open(BlockKind::Key, None),
+            BlockOpen::SnippetBlockOpen(..) => BlockTag::Open(BlockKind::Snippet, None),
+            BlockOpen::Unknown(..) => return None,
+        },
+        MustacheItem::BlockContinuation(continuation) => match continuation {
+            BlockContinuation::ElseBlock(..) => BlockTag::Continuation(BranchKind::Else),
+            BlockContinuation::ElseIfBlock(..) => BlockTag::Continuation(BranchKind::ElseIf),
+            BlockContinuation::ThenBlock(..) => BlockTag::Continuation(BranchKind::Then),
+            BlockContinuation::CatchBlock(..) => BlockTag::Continuation(BranchKind::Catch),
+            BlockContinuation::Unknown(..) => return None,
+        },
+        MustacheItem::BlockClose(close) => match close {
+            BlockClose::IfClose(..) => BlockTag::Close(BlockKind::If),
+            BlockClose::EachClose(..) => BlockTag::Close(BlockKind::Each),
+            BlockClose::AwaitClose(..) => BlockTag::Close(BlockKind::Await),
+            BlockClose::KeyClose(..) => BlockTag::Close(BlockKind::Key),
+            BlockClose::SnippetClose(..) => BlockTag::Close(BlockKind::Snippet),
+            BlockClose::Unknown(..) => return None,
+        },
+        _ => return None,
+    };
+    Some(tag)
+}
+
+/// The length of the parenthesised parameter list at the start of `text`, if there is one. Like
+/// the svelte parser, this only counts parentheses to find the end.
+fn snippet_params_len(text: &str) -> Option<usize> {
+    if !text.starts_with('(') {
+        return None;
+    }
+
+    let mut depth = 0;
+    for (index, c) in text.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(index + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Takes the `as` of an each block off a TypeScript expression, which leaves the expression before
+/// the last top-level `as`, like svelte does. The `as` applies to the rightmost operand, so
+/// `a || b as item` is `a || (b as item)`, and with an index `items as item, i` is a sequence
+/// starting with it. Returns `false` if there was no `as` to take.
+fn take_each_as(expression: &mut Box<Expr>) -> bool {
+    match &mut **expression {
+        Expr::TsAs(ts_as) => {
+            let inner = ts_as.expr.take();
+            *expression = inner;
+            true
+        }
+        Expr::Bin(bin) => {
+            let found = take_each_as(&mut bin.right);
+            bin.span.hi = bin.right.span_hi();
+            found
+        }
+        Expr::Cond(cond) => {
+            let found = take_each_as(&mut cond.alt);
+            cond.span.hi = cond.alt.span_hi();
+            found
+        }
+        Expr::Seq(seq) => {
+            if !seq.exprs.first_mut().is_some_and(take_each_as) {
+                return false;
+            }
+            let first = seq.exprs.swap_remove(0);
+            *expression = first;
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Reports the error from an expression that couldn't be parsed, and skips the rest of the
+/// mustache.
+fn invalid_expression(parser: &mut Parser, error: ParseError) -> Box<Expr> {
+    let span = skip_invalid_js(parser, error);
+    Box::new(Expr::Invalid(Invalid { span }))
+}
+
+/// Reports the error from a pattern that couldn't be parsed, and skips the rest of the mustache.
+fn invalid_pattern(parser: &mut Parser, error: ParseError) -> Pat {
+    let span = skip_invalid_js(parser, error);
+    Pat::Invalid(Invalid { span })
+}
+
+fn skip_invalid_js(parser: &mut Parser, mut error: ParseError) -> Span {
+    let span = parser.eat_to_mustache_close();
+    // swc doesn't say where it failed when it panics, so the error covers everything skipped
+    if error.kind == ParseErrorKind::JavaScriptParserFailed {
+        error.span = span;
+    }
+    parser.report_error(error);
+    span
+}
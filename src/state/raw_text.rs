@@ -0,0 +1,48 @@
+use super::{fragment::FragmentState, tag::TagState, State, StateTransition};
+use crate::{
+    error::ParseErrorKind,
+    parser::{new_span, Parser},
+    syntax_nodes::{StartTag, Text},
+    tokens::EndTagOpenToken,
+};
+
+/// Parses the contents of a `<script>` or `<style>` that isn't at the top level, like one inside
+/// `<svelte:head>`. Nothing inside of it is tokenized, so it's kept as a single text node.
+#[derive(Debug)]
+pub struct RawTextState {
+    pub start_tag: StartTag,
+}
+
+impl StateTransition for RawTextState {
+    fn next_state(self, parser: &mut Parser<'_>) -> State {
+        let start = parser.position();
+        let end = parser.text()[start..]
+            .find(&format!("</{}", self.start_tag.tag_name.text))
+            .map_or(parser.text().len(), |index| start + index);
+        parser.eat_to(end);
+
+        let span = new_span(start, end);
+        let children = if start < end {
+            let text = parser.text_span(&span).to_string();
+            vec![Text {
+                data: text.clone(),
+                text,
+                span,
+            }
+            .into()]
+        } else {
+            Vec::new()
+        };
+
+        let end_tag = match parser.eat_chars("</") {
+            Some(span) => TagState.parse_end_tag(parser, EndTagOpenToken { span }),
+            None => {
+                parser.error_with_span(ParseErrorKind::ElementNotClosed, self.start_tag.span);
+                None
+            }
+        };
+        parser.push_element(self.start_tag, children, end_tag);
+
+        FragmentState.into()
+    }
+}
@@ -0,0 +1,128 @@
+use super::{fragment::FragmentState, tag::TagState, State, StateTransition};
+use crate::{
+    error::{Diagnostic, DiagnosticTag, ParseErrorKind},
+    parser::{new_span, Parser},
+    syntax_nodes::{AttributeItem, InstanceScript, ModuleScript, Node, StartTag},
+    tokens::EndTagOpenToken,
+};
+use swc_common::{BytePos, Span, Spanned};
+use swc_ecma_ast::Module;
+
+/// Parses the contents of a top level `<script>`, after its start tag has been parsed.
+#[derive(Debug)]
+pub struct ScriptState {
+    pub start_tag: StartTag,
+}
+
+impl StateTransition for ScriptState {
+    fn next_state(self, parser: &mut Parser<'_>) -> State {
+        let start = parser.position();
+        let end = parser.text()[start..]
+            .find("</script")
+            .map_or(parser.text().len(), |index| start + index);
+        parser.eat_to(end);
+        let module = self.parse_module(parser, new_span(start, end));
+
+        let end_tag = match parser.eat_chars("</") {
+            Some(span) => TagState.parse_end_tag(parser, EndTagOpenToken { span }),
+            None => {
+                parser.error_with_span(ParseErrorKind::ElementNotClosed, self.start_tag.span);
+                None
+            }
+        };
+
+        let span = self.start_tag.span.with_hi(match &end_tag {
+            Some(end_tag) => end_tag.span_hi(),
+            None => BytePos(end as u32),
+        });
+        if self.is_module_context(parser) {
+            if parser
+                .top_level_nodes()
+                .iter()
+                .any(|node| matches!(node, Node::ModuleScript(..)))
+            {
+                parser.error_with_span(ParseErrorKind::DuplicateModuleScript, self.start_tag.span);
+            }
+
+            parser.push_node(ModuleScript {
+                start_tag: self.start_tag,
+                module,
+                end_tag,
+                span,
+            });
+        } else {
+            if parser
+                .top_level_nodes()
+                .iter()
+                .any(|node| matches!(node, Node::InstanceScript(..)))
+            {
+                parser
+                    .error_with_span(ParseErrorKind::DuplicateInstanceScript, self.start_tag.span);
+            }
+
+            parser.push_node(InstanceScript {
+                start_tag: self.start_tag,
+                module,
+                end_tag,
+                span,
+            });
+        }
+
+        FragmentState.into()
+    }
+}
+
+impl ScriptState {
+    fn parse_module(&self, parser: &mut Parser<'_>, span: Span) -> Module {
+        let text = parser.text_span(&span);
+        match parser.parse_js(text, span, |js_parser| js_parser.parse_module()) {
+            Ok(module) => module,
+            Err(error) => {
+                parser.report_error(error);
+                Module {
+                    span,
+                    body: Default::default(),
+                    shebang: None,
+                }
+            }
+        }
+    }
+
+    /// Whether this is a `<script context="module">` (or svelte 5's `<script module>`), rather
+    /// than the instance script.
+    fn is_module_context(&self, parser: &mut Parser<'_>) -> bool {
+        let mut is_module = false;
+        for attribute in &self.start_tag.attributes {
+            let AttributeItem::HtmlAttribute(attribute) = &attribute.attribute_item else {
+                continue;
+            };
+
+            match (
+                attribute.attribute_name.text.as_str(),
+                &attribute.attribute_initializer,
+            ) {
+                ("module", None) => is_module = true,
+                ("context", Some(initializer)) => {
+                    let value = &initializer.attribute_value;
+                    match value.static_text() {
+                        Some("module") => {
+                            is_module = true;
+                            parser.diagnostic(
+                                Diagnostic::warning(
+                                    ParseErrorKind::DeprecatedScriptContext,
+                                    attribute.span,
+                                )
+                                .with_tag(DiagnosticTag::Deprecated),
+                            );
+                        }
+                        _ => {
+                            parser.error_with_span(ParseErrorKind::InvalidScriptContext, value.span)
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        is_module
+    }
+}
@@ -0,0 +1,708 @@
+use super::{fragment::FragmentState, tag::TagState, State, StateTransition};
+use crate::{
+    error::ParseErrorKind,
+    parser::{new_span, Parser},
+    syntax_nodes::{
+        CssAtRule, CssAttributeSelector, CssBlock, CssBlockItem, CssClassSelector, CssCombinator,
+        CssComplexSelector, CssDeclaration, CssGlobalSelector, CssIdSelector, CssNestingSelector,
+        CssNth, CssPercentage, CssPseudoClassSelector, CssPseudoElementSelector,
+        CssRelativeSelector, CssRule, CssSelectorList, CssSimpleSelector, CssStyleRule,
+        CssTypeSelector, Node, StartTag, Style, StyleSheet,
+    },
+    tokens::{AmpersandToken, EndTagOpenToken},
+};
+use swc_common::{BytePos, Spanned};
+
+/// Parses the contents of a top level `<style>`, after its start tag has been parsed.
+#[derive(Debug)]
+pub struct StyleState {
+    pub start_tag: StartTag,
+}
+
+impl StateTransition for StyleState {
+    fn next_state(self, parser: &mut Parser<'_>) -> State {
+        let start = parser.position();
+        let end = parser.text()[start..]
+            .find("</style")
+            .map_or(parser.text().len(), |index| start + index);
+        let style_sheet = CssReader::new(parser, start, end).read_style_sheet();
+        parser.eat_to(end);
+
+        let end_tag = match parser.eat_chars("</") {
+            Some(span) => TagState.parse_end_tag(parser, EndTagOpenToken { span }),
+            None => {
+                parser.error_with_span(ParseErrorKind::ElementNotClosed, self.start_tag.span);
+                None
+            }
+        };
+
+        if parser
+            .top_level_nodes()
+            .iter()
+            .any(|node| matches!(node, Node::Style(..)))
+        {
+            parser.error_with_span(ParseErrorKind::DuplicateStyle, self.start_tag.span);
+        }
+
+        parser.push_node(Style {
+            span: self.start_tag.span.with_hi(match &end_tag {
+                Some(end_tag) => end_tag.span_hi(),
+                None => BytePos(end as u32),
+            }),
+            start_tag: self.start_tag,
+            style_sheet,
+            end_tag,
+        });
+
+        FragmentState.into()
+    }
+}
+
+/// A small recursive descent reader for the contents of a `<style>`, following the structure of
+/// svelte's own css parser. Unlike the template parser it needs to backtrack, so it works on byte
+/// indexes into the source rather than the muncher; spans are still relative to the whole source.
+struct CssReader<'a, 'p> {
+    parser: &'p mut Parser<'a>,
+    text: &'a str,
+    index: usize,
+    end: usize,
+}
+
+impl<'a, 'p> CssReader<'a, 'p> {
+    fn new(parser: &'p mut Parser<'a>, start: usize, end: usize) -> Self {
+        Self {
+            text: parser.text(),
+            parser,
+            index: start,
+            end,
+        }
+    }
+
+    fn read_style_sheet(mut self) -> StyleSheet {
+        let start = self.index;
+        let mut rules = Vec::new();
+        loop {
+            self.allow_comment_or_whitespace();
+            if self.is_eof() {
+                break;
+            }
+
+            let before = self.index;
+            rules.push(if self.matches("@") {
+                CssRule::from(self.read_at_rule())
+            } else {
+                self.read_style_rule().into()
+            });
+            self.ensure_progress(before);
+        }
+
+        StyleSheet {
+            rules,
+            span: new_span(start, self.end),
+        }
+    }
+
+    fn read_at_rule(&mut self) -> CssAtRule {
+        let start = self.index;
+        self.eat("@");
+        let name = self.read_identifier();
+        let prelude = self.read_value();
+        let css_block = if self.matches("{") {
+            Some(self.read_block())
+        } else {
+            self.require(';');
+            None
+        };
+
+        CssAtRule {
+            name,
+            prelude,
+            css_block,
+            span: new_span(start, self.index),
+        }
+    }
+
+    fn read_style_rule(&mut self) -> CssStyleRule {
+        let start = self.index;
+        let css_selector_list = self.read_selector_list(false);
+        let css_block = self.read_block();
+
+        CssStyleRule {
+            css_selector_list,
+            css_block,
+            span: new_span(start, self.index),
+        }
+    }
+
+    fn read_block(&mut self) -> CssBlock {
+        let start = self.index;
+        if !self.eat("{") {
+            self.error_here(ParseErrorKind::ExpectedCssToken('{'));
+            // Skip the rest of the broken rule so that the following rules can still be read.
+            self.index = self.text[self.index..self.end]
+                .find('}')
+                .map_or(self.end, |index| self.index + index + 1);
+            return CssBlock {
+                children: Vec::new(),
+                span: new_span(start, self.index),
+            };
+        }
+
+        let mut children = Vec::new();
+        loop {
+            self.allow_comment_or_whitespace();
+            if self.matches("}") || self.is_eof() {
+                break;
+            }
+
+            let before = self.index;
+            children.push(self.read_block_item());
+            self.ensure_progress(before);
+        }
+        self.require('}');
+
+        CssBlock {
+            children,
+            span: new_span(start, self.index),
+        }
+    }
+
+    fn read_block_item(&mut self) -> CssBlockItem {
+        if self.matches("@") {
+            return self.read_at_rule().into();
+        }
+
+        // Read ahead to find out whether this is a declaration or a nested rule.
+        let start = self.index;
+        self.read_value();
+        let is_rule = self.matches("{");
+        self.index = start;
+
+        if is_rule {
+            self.read_style_rule().into()
+        } else {
+            self.read_declaration().into()
+        }
+    }
+
+    fn read_declaration(&mut self) -> CssDeclaration {
+        let start = self.index;
+        let property_end = self.text[start..self.end]
+            .find(|c: char| c.is_whitespace() || c == ':')
+            .map_or(self.end, |index| start + index);
+        let property = self.text[start..property_end].to_string();
+        self.index = property_end;
+        self.allow_whitespace();
+        self.eat(":");
+        let colon_end = self.index;
+        self.allow_whitespace();
+        let value = self.read_value();
+        if value.is_empty() && !property.starts_with("--") {
+            self.error(ParseErrorKind::EmptyCssDeclaration, start, colon_end);
+        }
+
+        let span = new_span(start, self.index);
+        if !self.matches("}") {
+            self.require(';');
+        }
+
+        CssDeclaration {
+            property,
+            value,
+            span,
+        }
+    }
+
+    fn read_selector_list(&mut self, inside_pseudo_class: bool) -> CssSelectorList {
+        self.allow_comment_or_whitespace();
+        let start = self.index;
+        let mut selectors = Vec::new();
+        let mut end = start;
+        while !self.is_eof() {
+            selectors.push(self.read_selector(inside_pseudo_class));
+            end = self.index;
+            self.allow_comment_or_whitespace();
+            if self.matches_selector_end(inside_pseudo_class) {
+                break;
+            } else if self.eat(",") {
+                self.allow_comment_or_whitespace();
+            } else {
+                let token = if inside_pseudo_class { ')' } else { '{' };
+                self.error_here(ParseErrorKind::ExpectedCssToken(token));
+                break;
+            }
+        }
+
+        CssSelectorList {
+            selectors,
+            span: new_span(start, end),
+        }
+    }
+
+    fn read_selector(&mut self, inside_pseudo_class: bool) -> CssComplexSelector {
+        let list_start = self.index;
+        let mut relative_selectors = Vec::new();
+        let mut relative_start = self.index;
+        let mut css_combinator = None;
+        let mut selectors = Vec::new();
+
+        while !self.is_eof() {
+            let start = self.index;
+            if let Some(selector) = self.read_simple_selector(inside_pseudo_class) {
+                selectors.push(selector);
+            }
+
+            let index = self.index;
+            self.allow_comment_or_whitespace();
+            if self.matches(",") || self.matches_selector_end(inside_pseudo_class) {
+                self.index = index;
+                break;
+            }
+
+            self.index = index;
+            if let Some(combinator) = self.read_combinator() {
+                if !selectors.is_empty() {
+                    relative_selectors.push(CssRelativeSelector {
+                        css_combinator: css_combinator.take(),
+                        selectors: std::mem::take(&mut selectors),
+                        span: new_span(relative_start, index),
+                    });
+                }
+                relative_start = combinator.span.lo.0 as usize;
+                css_combinator = Some(combinator);
+
+                self.allow_whitespace();
+                if self.matches(",") || self.matches_selector_end(inside_pseudo_class) {
+                    self.error_here(ParseErrorKind::InvalidCssSelector);
+                }
+            } else if self.index == start {
+                // Nothing could be read, which has already been reported.
+                break;
+            }
+        }
+
+        let end = self.index;
+        relative_selectors.push(CssRelativeSelector {
+            css_combinator,
+            selectors,
+            span: new_span(relative_start, end),
+        });
+
+        CssComplexSelector {
+            relative_selectors,
+            span: new_span(list_start, end),
+        }
+    }
+
+    fn read_simple_selector(&mut self, inside_pseudo_class: bool) -> Option<CssSimpleSelector> {
+        let start = self.index;
+        let selector = if self.eat("&") {
+            CssNestingSelector {
+                ampersand: AmpersandToken {
+                    span: new_span(start, self.index),
+                },
+                span: new_span(start, self.index),
+            }
+            .into()
+        } else if self.eat("*") {
+            let name = if self.eat("|") {
+                self.read_identifier()
+            } else {
+                "*".to_string()
+            };
+            CssTypeSelector {
+                name,
+                span: new_span(start, self.index),
+            }
+            .into()
+        } else if self.eat("#") {
+            CssIdSelector {
+                name: self.read_identifier(),
+                span: new_span(start, self.index),
+            }
+            .into()
+        } else if self.eat(".") {
+            CssClassSelector {
+                name: self.read_identifier(),
+                span: new_span(start, self.index),
+            }
+            .into()
+        } else if self.eat("::") {
+            let name = self.read_identifier();
+            // The arguments of pseudo elements are read to make sure they're valid, but not kept.
+            if self.eat("(") {
+                self.read_selector_list(true);
+                self.require(')');
+            }
+            CssPseudoElementSelector {
+                name,
+                span: new_span(start, self.index),
+            }
+            .into()
+        } else if self.eat(":") {
+            let name = self.read_identifier();
+            let args = if self.eat("(") {
+                let args = self.read_selector_list(true);
+                self.require(')');
+                Some(args)
+            } else {
+                None
+            };
+            let span = new_span(start, self.index);
+            if name == "global" {
+                CssGlobalSelector { args, span }.into()
+            } else {
+                CssPseudoClassSelector { name, args, span }.into()
+            }
+        } else if self.eat("[") {
+            self.read_attribute_selector(start).into()
+        } else if let Some(len) = inside_pseudo_class.then(|| nth_len(self.rest())).flatten() {
+            // This has to come before combinators, otherwise the `+` in `+2n-1` would be read as one.
+            CssNth {
+                value: self.read_len(len),
+                span: new_span(start, self.index),
+            }
+            .into()
+        } else if let Some(len) = percentage_len(self.rest()) {
+            CssPercentage {
+                value: self.read_len(len),
+                span: new_span(start, self.index),
+            }
+            .into()
+        } else if combinator_len(self.rest()).is_none() {
+            let mut name = self.read_identifier();
+            if self.eat("|") {
+                name = self.read_identifier();
+            }
+            if name.is_empty() {
+                return None;
+            }
+            CssTypeSelector {
+                name,
+                span: new_span(start, self.index),
+            }
+            .into()
+        } else {
+            return None;
+        };
+
+        Some(selector)
+    }
+
+    fn read_attribute_selector(&mut self, start: usize) -> CssAttributeSelector {
+        self.allow_whitespace();
+        let name = self.read_identifier();
+        self.allow_whitespace();
+
+        let mut value = None;
+        let mut flags = None;
+        let matcher = attribute_matcher_len(self.rest()).map(|len| self.read_len(len));
+        if matcher.is_some() {
+            self.allow_whitespace();
+            value = Some(self.read_attribute_value());
+            self.allow_whitespace();
+            let len = self.rest().len()
+                - self
+                    .rest()
+                    .trim_start_matches(|c: char| c.is_ascii_alphabetic())
+                    .len();
+            flags = (len > 0).then(|| self.read_len(len));
+            self.allow_whitespace();
+        }
+        self.require(']');
+
+        CssAttributeSelector {
+            name,
+            matcher,
+            value,
+            flags,
+            span: new_span(start, self.index),
+        }
+    }
+
+    fn read_attribute_value(&mut self) -> String {
+        let quote = ['"', '\''].into_iter().find(|quote| self.eat_char(*quote));
+        let mut value = String::new();
+        let mut escaped = false;
+        while let Some(c) = self.peek() {
+            if escaped {
+                value.push('\\');
+                value.push(c);
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if quote.map_or(c.is_whitespace() || c == ']', |quote| c == quote) {
+                if quote.is_some() {
+                    self.index += c.len_utf8();
+                }
+                return value.trim().to_string();
+            } else {
+                value.push(c);
+            }
+            self.index += c.len_utf8();
+        }
+
+        self.error_here(ParseErrorKind::ExpectedCssToken(quote.unwrap_or(']')));
+        value.trim().to_string()
+    }
+
+    fn read_combinator(&mut self) -> Option<CssCombinator> {
+        let start = self.index;
+        self.allow_whitespace();
+        let index = self.index;
+        if let Some(len) = combinator_len(self.rest()) {
+            let text = self.read_len(len);
+            let span = new_span(index, self.index);
+            self.allow_whitespace();
+            return Some(CssCombinator { text, span });
+        }
+
+        (self.index != start).then(|| CssCombinator {
+            text: " ".to_string(),
+            span: new_span(start, self.index),
+        })
+    }
+
+    /// Reads an at-rule prelude or declaration value, up until the `;`, `{` or `}` ending it.
+    fn read_value(&mut self) -> String {
+        let start = self.index;
+        let mut escaped = false;
+        let mut in_url = false;
+        let mut quote = None;
+        while let Some(c) = self.peek() {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if Some(c) == quote {
+                quote = None;
+            } else if c == ')' {
+                in_url = false;
+            } else if quote.is_none() && matches!(c, '"' | '\'') {
+                quote = Some(c);
+            } else if c == '(' && self.text[start..self.index].ends_with("url") {
+                in_url = true;
+            } else if matches!(c, ';' | '{' | '}') && !in_url && quote.is_none() {
+                break;
+            }
+            self.index += c.len_utf8();
+        }
+
+        self.text[start..self.index].trim().to_string()
+    }
+
+    fn read_identifier(&mut self) -> String {
+        let start = self.index;
+        let rest = self.rest();
+        if rest.starts_with("--")
+            || rest.starts_with(|c: char| c.is_ascii_digit())
+            || rest
+                .strip_prefix('-')
+                .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_digit()))
+        {
+            self.error(ParseErrorKind::ExpectedCssIdentifier, start, start + 1);
+        }
+
+        let mut identifier = String::new();
+        let mut escaped = false;
+        while let Some(c) = self.peek() {
+            if escaped {
+                identifier.push('\\');
+                identifier.push(c);
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c as u32 >= 160 || c.is_ascii_alphanumeric() || matches!(c, '_' | '-') {
+                identifier.push(c);
+            } else {
+                break;
+            }
+            self.index += c.len_utf8();
+        }
+
+        if identifier.is_empty() {
+            self.error(ParseErrorKind::ExpectedCssIdentifier, start, start + 1);
+        }
+        identifier
+    }
+
+    fn allow_whitespace(&mut self) {
+        let rest = self.rest();
+        self.index += rest.len() - rest.trim_start().len();
+    }
+
+    fn allow_comment_or_whitespace(&mut self) {
+        self.allow_whitespace();
+        while self.eat("/*") {
+            self.index = self.text[self.index..self.end]
+                .find("*/")
+                .map_or(self.end, |index| self.index + index + 2);
+            self.allow_whitespace();
+        }
+    }
+
+    /// Makes sure a loop reading a list of items moves forward, skipping a character if nothing
+    /// was read.
+    fn ensure_progress(&mut self, before: usize) {
+        if self.index == before {
+            if let Some(c) = self.peek() {
+                self.index += c.len_utf8();
+            }
+        }
+    }
+
+    fn matches_selector_end(&self, inside_pseudo_class: bool) -> bool {
+        self.matches(if inside_pseudo_class { ")" } else { "{" })
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.text[self.index..self.end]
+    }
+
+    fn is_eof(&self) -> bool {
+        self.index >= self.end
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn matches(&self, s: &str) -> bool {
+        self.rest().starts_with(s)
+    }
+
+    fn eat(&mut self, s: &str) -> bool {
+        let matches = self.matches(s);
+        if matches {
+            self.index += s.len();
+        }
+        matches
+    }
+
+    fn eat_char(&mut self, c: char) -> bool {
+        self.eat(c.encode_utf8(&mut [0; 4]))
+    }
+
+    fn require(&mut self, c: char) {
+        if !self.eat_char(c) {
+            self.error_here(ParseErrorKind::ExpectedCssToken(c));
+        }
+    }
+
+    fn read_len(&mut self, len: usize) -> String {
+        let start = self.index;
+        self.index += len;
+        self.text[start..self.index].to_string()
+    }
+
+    fn error_here(&mut self, kind: ParseErrorKind) {
+        let end = self
+            .peek()
+            .map_or(self.index, |c| self.index + c.len_utf8());
+        self.error(kind, self.index, end);
+    }
+
+    fn error(&mut self, kind: ParseErrorKind, start: usize, end: usize) {
+        self.parser.error_with_span(kind, new_span(start, end));
+    }
+}
+
+fn digits_len(s: &str) -> usize {
+    s.len() - s.trim_start_matches(|c: char| c.is_ascii_digit()).len()
+}
+
+fn whitespace_len(s: &str) -> usize {
+    s.len() - s.trim_start().len()
+}
+
+/// Length of a `+`, `~`, `>` or `||` combinator at the start of `s`.
+fn combinator_len(s: &str) -> Option<usize> {
+    if s.starts_with("||") {
+        Some(2)
+    } else if s.starts_with(['+', '~', '>']) {
+        Some(1)
+    } else {
+        None
+    }
+}
+
+/// Length of a `=`, `~=`, `^=`, `$=`, `*=` or `|=` attribute matcher at the start of `s`.
+fn attribute_matcher_len(s: &str) -> Option<usize> {
+    if s.starts_with('=') {
+        Some(1)
+    } else if s.starts_with(['~', '^', '$', '*', '|']) && s[1..].starts_with('=') {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+/// Length of a keyframe selector like `50%` or `12.5%` at the start of `s`.
+fn percentage_len(s: &str) -> Option<usize> {
+    let mut len = digits_len(s);
+    if len == 0 {
+        return None;
+    }
+    if let Some(rest) = s[len..].strip_prefix('.') {
+        let fraction = digits_len(rest);
+        if fraction == 0 {
+            return None;
+        }
+        len += 1 + fraction;
+    }
+    s[len..].starts_with('%').then_some(len + 1)
+}
+
+/// Length of an `an+b` argument to `:nth-child()` and friends at the start of `s`, including a
+/// following ` of ` if there is one.
+fn nth_len(s: &str) -> Option<usize> {
+    let len = if s.starts_with("odd") {
+        3
+    } else if s.starts_with("even") {
+        4
+    } else {
+        let (sign, negative) = match s.as_bytes().first() {
+            Some(b'+') => (1, false),
+            Some(b'-') => (1, true),
+            _ => (0, false),
+        };
+        let digits = digits_len(&s[sign..]);
+        let mut len = sign + digits;
+        if s[len..].starts_with('n') {
+            len += 1;
+            let offset = offset_len(&s[len..], negative);
+            if negative && offset.is_none() {
+                return None;
+            }
+            len += offset.unwrap_or(0);
+        } else if negative || digits == 0 {
+            return None;
+        }
+        len
+    };
+
+    let rest = &s[len..];
+    let whitespace = whitespace_len(rest);
+    if rest[whitespace..].starts_with([',', ')']) {
+        return Some(len);
+    }
+    let of = rest[whitespace..].strip_prefix("of")?;
+    if whitespace == 0 || whitespace_len(of) == 0 {
+        return None;
+    }
+    Some(len + whitespace + 2 + whitespace_len(of))
+}
+
+/// Length of the `+ b` in `an + b`. When `a` is negative only a positive offset is allowed.
+fn offset_len(s: &str, negative: bool) -> Option<usize> {
+    let before_sign = whitespace_len(s);
+    let rest = &s[before_sign..];
+    let sign = if rest.starts_with('+') || (!negative && rest.starts_with('-')) {
+        1
+    } else {
+        return None;
+    };
+    let after_sign = whitespace_len(&rest[sign..]);
+    let digits = digits_len(&rest[sign + after_sign..]);
+    (digits > 0).then_some(before_sign + sign + after_sign + digits)
+}
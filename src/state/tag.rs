@@ -1,9 +1,27 @@
-use super::{State, StateTransition};
+use super::{
+    mustache::MustacheState, raw_text::RawTextState, script::ScriptState, style::StyleState, State,
+    StateTransition,
+};
 use crate::{
-    parser::Parser,
-    syntax_nodes::{Comment, CommentText},
-    tokens::{CommentEndToken, CommentStartToken},
+    error::ParseErrorKind,
+    fix::Fix,
+    html::{decode_character_references, is_raw_text_element, is_void_element},
+    parser::{new_span, Parser},
+    syntax_nodes::{
+        Attribute, AttributeInitializer, AttributeItem, AttributeMustache, AttributeName,
+        AttributeValue, AttributeValuePart, Comment, CommentText, Directive, DirectiveKind,
+        DirectiveModifier, DirectiveName, EndTag, HtmlAttribute, InvalidSyntax, ShorthandAttribute,
+        SpreadAttribute, StartTag, StartTagClose, TagName, Text,
+    },
+    tokens::{
+        AnimateToken, BindToken, ClassToken, ColonToken, CommentEndToken, CommentStartToken,
+        EndTagOpenToken, EqToken, InToken, LetToken, MustacheCloseToken, MustacheOpenToken,
+        OnToken, OutToken, QuoteToken, SelfClosingTagCloseToken, SpreadToken, StyleToken,
+        TagCloseToken, TagOpenToken, TransitionToken, UseToken, WhitespaceToken,
+    },
 };
+use swc_common::{Span, Spanned};
+use swc_ecma_ast::{Expr, Invalid};
 
 #[derive(Debug)]
 pub struct TagState;
@@ -26,10 +44,497 @@ impl StateTransition for TagState {
                 comment_end: end_span.map(|span| CommentEndToken { span }),
                 span,
             });
+        } else if let Some(span) = parser.eat_chars("</") {
+            if let Some(end_tag) = self.parse_end_tag(parser, EndTagOpenToken { span }) {
+                parser.close_element(end_tag);
+            }
+        } else if let Some(span) = parser.eat_char('<') {
+            return self.parse_start_tag(parser, TagOpenToken { span });
+        }
+
+        State::default()
+    }
+}
+
+impl TagState {
+    fn parse_tag_name(&self, parser: &mut Parser<'_>) -> Option<TagName> {
+        let span = parser.eat_until(|c| c.is_ascii_whitespace() || matches!(c, '/' | '>' | '<'));
+        if span.lo == span.hi {
+            return None;
+        }
 
+        Some(TagName {
+            text: parser.text_span(&span).to_string(),
+            span,
+        })
+    }
+
+    fn parse_start_tag(self, parser: &mut Parser<'_>, tag_open: TagOpenToken) -> State {
+        let Some(tag_name) = self.parse_tag_name(parser) else {
+            self.invalid_tag(parser, tag_open.span);
             return State::default();
+        };
+        let (attributes, trailing_whitespace) = self.parse_attributes(parser);
+
+        let start_tag_close = if let Some(span) = parser.eat_chars("/>") {
+            Some(SelfClosingTagCloseToken { span }.into())
+        } else if let Some(span) = parser.eat_char('>') {
+            Some(TagCloseToken { span }.into())
+        } else {
+            parser.error(ParseErrorKind::TagNotClosed);
+            None
+        };
+
+        let start_tag = StartTag {
+            span: parser.span_from(tag_open.span.lo.0 as usize),
+            tag_open,
+            tag_name,
+            attributes,
+            trailing_whitespace,
+            start_tag_close,
+        };
+
+        parser.close_omitted_element(&start_tag.tag_name.text);
+        if matches!(
+            start_tag.start_tag_close,
+            Some(StartTagClose::SelfClosingTagClose(..))
+        ) || is_void_element(&start_tag.tag_name.text)
+        {
+            parser.push_element(start_tag, Default::default(), None);
+        } else if start_tag.tag_name.text == "script" && parser.is_top_level() {
+            return ScriptState { start_tag }.into();
+        } else if start_tag.tag_name.text == "style" && parser.is_top_level() {
+            return StyleState { start_tag }.into();
+        } else if is_raw_text_element(&start_tag.tag_name.text) {
+            return RawTextState { start_tag }.into();
+        } else {
+            parser.open_element(start_tag);
+        }
+
+        State::default()
+    }
+
+    pub(super) fn parse_end_tag(
+        self,
+        parser: &mut Parser<'_>,
+        end_tag_open: EndTagOpenToken,
+    ) -> Option<EndTag> {
+        let Some(tag_name) = self.parse_tag_name(parser) else {
+            self.invalid_tag(parser, end_tag_open.span);
+            return None;
+        };
+        let whitespace = parser.allow_whitespace();
+
+        let tag_close = match parser.eat_char('>') {
+            Some(span) => Some(TagCloseToken { span }),
+            None => {
+                parser.error(ParseErrorKind::TagNotClosed);
+                None
+            }
+        };
+
+        Some(EndTag {
+            span: parser.span_from(end_tag_open.span.lo.0 as usize),
+            end_tag_open,
+            tag_name,
+            whitespace,
+            tag_close,
+        })
+    }
+
+    /// Parses attributes up until the end of the start tag, returning them along with any
+    /// whitespace between the last attribute and the end of the tag.
+    fn parse_attributes(
+        &self,
+        parser: &mut Parser<'_>,
+    ) -> (Vec<Attribute>, Option<WhitespaceToken>) {
+        let mut attributes = Vec::new();
+        loop {
+            let start = parser.position();
+            let whitespace = parser.allow_whitespace();
+            let attribute_item = match parser.peek() {
+                None | Some('>' | '/') => return (attributes, whitespace),
+                Some('{') if is_spread(parser) => self.parse_spread_attribute(parser).into(),
+                Some('{') => self.parse_shorthand_attribute(parser).into(),
+                Some(_) => self.parse_html_attribute(parser),
+            };
+
+            attributes.push(Attribute {
+                whitespace,
+                attribute_item,
+                span: parser.span_from(start),
+            });
+        }
+    }
+
+    fn parse_html_attribute(&self, parser: &mut Parser<'_>) -> AttributeItem {
+        let start = parser.position();
+
+        let prefix_span = parser.peek_until(|c| *c == ':' || is_attribute_name_end(*c));
+        if parser.text()[prefix_span.hi.0 as usize..].starts_with(':') {
+            let prefix = parser.text_span(&prefix_span);
+            if let Some(directive_kind) = parse_directive_kind(prefix, prefix_span) {
+                parser.eat_to_span_hi(&prefix_span);
+                return self.parse_directive(parser, directive_kind).into();
+            }
+            if !matches!(prefix, "xmlns" | "xlink" | "xml") {
+                parser.error_with_span(ParseErrorKind::UnknownDirectiveType, prefix_span);
+            }
         }
 
-        todo!()
+        let name_span = parser.eat_until(|c| is_attribute_name_end(*c));
+        if name_span.lo == name_span.hi {
+            let span = parser.eat_until(|c| c.is_ascii_whitespace() || matches!(c, '/' | '>'));
+            parser.error_with_span(ParseErrorKind::ExpectedAttributeName, span);
+            return InvalidSyntax {
+                text: parser.text_span(&span).to_string(),
+                span,
+            }
+            .into();
+        }
+
+        let attribute_name = AttributeName {
+            text: parser.text_span(&name_span).to_string(),
+            span: name_span,
+        };
+        let attribute_initializer = match parser.peek_ignore_whitespace() {
+            Some('=') => Some(self.parse_attribute_initializer(parser)),
+            _ => None,
+        };
+
+        HtmlAttribute {
+            attribute_name,
+            attribute_initializer,
+            span: parser.span_from(start),
+        }
+        .into()
+    }
+
+    fn parse_directive(&self, parser: &mut Parser<'_>, directive_kind: DirectiveKind) -> Directive {
+        let colon = ColonToken {
+            span: parser
+                .eat_char(':')
+                .expect("should not parse directive without `:`"),
+        };
+
+        let name_span = parser.eat_until(|c| *c == '|' || is_attribute_name_end(*c));
+        if name_span.lo == name_span.hi {
+            parser.error_with_span(ParseErrorKind::ExpectedDirectiveName, colon.span);
+        }
+        let directive_name = DirectiveName {
+            text: parser.text_span(&name_span).to_string(),
+            span: name_span,
+        };
+
+        let mut modifiers = Vec::new();
+        while let Some(pipe_span) = parser.eat_char('|') {
+            let text_span = parser.eat_until(|c| *c == '|' || is_attribute_name_end(*c));
+            let modifier = DirectiveModifier {
+                pipe: pipe_span.into(),
+                text: parser.text_span(&text_span).to_string(),
+                span: pipe_span.with_hi(text_span.hi),
+            };
+            if !allowed_modifiers(&directive_kind).contains(&modifier.text.as_str()) {
+                parser.error_with_span(ParseErrorKind::IllegalDirectiveModifier, modifier.span);
+            }
+            modifiers.push(modifier);
+        }
+
+        let attribute_initializer = match parser.peek_ignore_whitespace() {
+            Some('=') => Some(self.parse_attribute_initializer(parser)),
+            _ => None,
+        };
+        if let Some(initializer) = &attribute_initializer {
+            // Only style directives can be given text, everything else needs a single expression
+            let value = &initializer.attribute_value;
+            let is_expression = matches!(
+                &value.parts[..],
+                [AttributeValuePart::AttributeMustache(..)]
+            );
+            if !is_expression && !matches!(directive_kind, DirectiveKind::Style(..)) {
+                parser.error_with_span(ParseErrorKind::InvalidDirectiveValue, value.span);
+            }
+        }
+
+        Directive {
+            span: parser.span_from(directive_kind.span_lo().0 as usize),
+            directive_kind,
+            colon,
+            directive_name,
+            modifiers,
+            attribute_initializer,
+        }
+    }
+
+    fn parse_attribute_initializer(&self, parser: &mut Parser<'_>) -> AttributeInitializer {
+        let start = parser.position();
+        let leading_ws = parser.allow_whitespace();
+        let eq = EqToken {
+            span: parser
+                .eat_char('=')
+                .expect("should not parse initializer without `=`"),
+        };
+        let trailing_ws = parser.allow_whitespace();
+        let attribute_value = self.parse_attribute_value(parser);
+
+        AttributeInitializer {
+            leading_ws,
+            eq,
+            trailing_ws,
+            attribute_value,
+            span: parser.span_from(start),
+        }
+    }
+
+    fn parse_attribute_value(&self, parser: &mut Parser<'_>) -> AttributeValue {
+        let start = parser.position();
+        let quote = match parser.peek() {
+            Some(c @ ('"' | '\'')) => Some(*c),
+            _ => None,
+        };
+        let open_quote = quote.and_then(|c| parser.eat_char(c)).map(QuoteToken::from);
+
+        let mut parts = Vec::new();
+        loop {
+            let part: AttributeValuePart = match parser.peek() {
+                None => break,
+                Some(c) if Some(*c) == quote => break,
+                Some(c) if quote.is_none() && is_unquoted_value_end(*c) => break,
+                Some(_) if quote.is_none() && parser.peek_chars("/>") => break,
+                Some('{') => self.parse_attribute_mustache(parser).into(),
+                Some(_) => self.parse_attribute_text(parser, quote).into(),
+            };
+            parts.push(part);
+        }
+
+        let close_quote = match quote {
+            Some(c) => match parser.eat_char(c) {
+                Some(span) => Some(QuoteToken { span }),
+                None => {
+                    parser.error(ParseErrorKind::AttributeValueNotClosed(c));
+                    None
+                }
+            },
+            None => {
+                if parts.is_empty() {
+                    parser.error(ParseErrorKind::ExpectedAttributeValue);
+                }
+                None
+            }
+        };
+
+        AttributeValue {
+            open_quote,
+            parts,
+            close_quote,
+            span: parser.span_from(start),
+        }
+    }
+
+    fn parse_attribute_text(&self, parser: &mut Parser<'_>, quote: Option<char>) -> Text {
+        let start = parser.position();
+        while let Some(&c) = parser.peek() {
+            let is_end = match quote {
+                Some(quote) => c == quote,
+                None => is_unquoted_value_end(c) || parser.peek_chars("/>"),
+            };
+            if is_end || c == '{' {
+                break;
+            }
+            parser.eat();
+        }
+
+        let span = parser.span_from(start);
+        let text = parser.text_span(&span);
+        Text {
+            text: text.to_string(),
+            data: decode_character_references(text, true),
+            span,
+        }
+    }
+
+    fn parse_attribute_mustache(&self, parser: &mut Parser<'_>) -> AttributeMustache {
+        let start = parser.position();
+        let mustache_open = MustacheOpenToken {
+            span: parser
+                .eat_char('{')
+                .expect("should not parse attribute mustache without moustache open"),
+        };
+        let leading_whitespace = parser.allow_whitespace();
+        let expression = match misplaced_tag(parser) {
+            Some(kind) => self.parse_misplaced_tag(parser, kind),
+            None => MustacheState.parse_js_expression(parser),
+        };
+        let trailing_whitespace = parser.allow_whitespace();
+
+        let mustache_close = match parser.eat_char('}') {
+            Some(span) => Some(MustacheCloseToken { span }),
+            None => {
+                let position = parser.position();
+                parser.error_with_fix(
+                    ParseErrorKind::MustacheNotClosed,
+                    Fix::insert("Insert missing `}`", position, "}"),
+                );
+                None
+            }
+        };
+
+        AttributeMustache {
+            mustache_open,
+            leading_whitespace,
+            expression,
+            trailing_whitespace,
+            mustache_close,
+            span: parser.span_from(start),
+        }
+    }
+
+    /// Skips over a `{@...}` tag in an attribute value, where it can't be used. It's kept as an
+    /// invalid expression, since there's no expression to parse.
+    fn parse_misplaced_tag(&self, parser: &mut Parser<'_>, kind: ParseErrorKind) -> Box<Expr> {
+        let start = parser.position();
+        let text = parser.text()[start..parser.find_mustache_close()].trim_end();
+        let span = new_span(start, start + text.len());
+        parser.eat_to_span_hi(&span);
+
+        parser.error_with_span(kind, span);
+        Box::new(Expr::Invalid(Invalid { span }))
+    }
+
+    fn parse_spread_attribute(&self, parser: &mut Parser<'_>) -> SpreadAttribute {
+        let start = parser.position();
+        let mustache_open = MustacheOpenToken {
+            span: parser
+                .eat_char('{')
+                .expect("should not parse spread attribute without moustache open"),
+        };
+        let leading_whitespace = parser.allow_whitespace();
+        let spread = match parser.eat_chars("...") {
+            Some(span) => SpreadToken { span },
+            None => {
+                parser.error(ParseErrorKind::ExpectedSpread);
+                let position = parser.position();
+                SpreadToken {
+                    span: new_span(position, position),
+                }
+            }
+        };
+        let expression = MustacheState.parse_js_expression(parser);
+        let trailing_whitespace = parser.allow_whitespace();
+
+        let mustache_close = match parser.eat_char('}') {
+            Some(span) => Some(MustacheCloseToken { span }),
+            None => {
+                let position = parser.position();
+                parser.error_with_fix(
+                    ParseErrorKind::MustacheNotClosed,
+                    Fix::insert("Insert missing `}`", position, "}"),
+                );
+                None
+            }
+        };
+
+        SpreadAttribute {
+            mustache_open,
+            leading_whitespace,
+            spread,
+            expression,
+            trailing_whitespace,
+            mustache_close,
+            span: parser.span_from(start),
+        }
+    }
+
+    fn parse_shorthand_attribute(&self, parser: &mut Parser<'_>) -> ShorthandAttribute {
+        let attribute_mustache = self.parse_attribute_mustache(parser);
+        if !matches!(
+            &*attribute_mustache.expression,
+            Expr::Ident(..) | Expr::Invalid(..)
+        ) {
+            parser.error_with_span(
+                ParseErrorKind::InvalidShorthandAttribute,
+                attribute_mustache.expression.span(),
+            );
+        }
+
+        ShorthandAttribute {
+            span: attribute_mustache.span,
+            attribute_mustache,
+        }
+    }
+
+    fn invalid_tag(self, parser: &mut Parser<'_>, span: Span) {
+        parser.error_with_span(ParseErrorKind::ExpectedTagName, span);
+        parser.push_node(InvalidSyntax {
+            text: parser.text_span(&span).to_string(),
+            span,
+        });
+    }
+}
+
+/// Whether the parser is at the `{` of a `{...spread}` mustache.
+fn is_spread(parser: &Parser<'_>) -> bool {
+    parser.text()[parser.position() + 1..]
+        .trim_start_matches(|c: char| c.is_ascii_whitespace())
+        .starts_with("...")
+}
+
+/// Characters that end the name of an attribute or directive.
+pub(crate) fn is_attribute_name_end(c: char) -> bool {
+    c.is_ascii_whitespace() || matches!(c, '=' | '/' | '>' | '"' | '\'')
+}
+
+fn parse_directive_kind(prefix: &str, span: Span) -> Option<DirectiveKind> {
+    Some(match prefix {
+        "on" => OnToken { span }.into(),
+        "bind" => BindToken { span }.into(),
+        "class" => ClassToken { span }.into(),
+        "style" => StyleToken { span }.into(),
+        "use" => UseToken { span }.into(),
+        "transition" => TransitionToken { span }.into(),
+        "in" => InToken { span }.into(),
+        "out" => OutToken { span }.into(),
+        "animate" => AnimateToken { span }.into(),
+        "let" => LetToken { span }.into(),
+        _ => return None,
+    })
+}
+
+fn allowed_modifiers(directive_kind: &DirectiveKind) -> &'static [&'static str] {
+    match directive_kind {
+        DirectiveKind::On(..) => &[
+            "preventDefault",
+            "stopPropagation",
+            "stopImmediatePropagation",
+            "capture",
+            "once",
+            "passive",
+            "nonpassive",
+            "self",
+            "trusted",
+        ],
+        DirectiveKind::Transition(..) | DirectiveKind::In(..) | DirectiveKind::Out(..) => {
+            &["local", "global"]
+        }
+        DirectiveKind::Style(..) => &["important"],
+        _ => &[],
+    }
+}
+
+/// Characters that end an unquoted attribute value, alongside `/>`.
+pub(crate) fn is_unquoted_value_end(c: char) -> bool {
+    c.is_ascii_whitespace() || matches!(c, '>' | '"' | '\'' | '=' | '<' | '`')
+}
+
+/// The error for a `{@...}` tag at the current position in an attribute value, if there is one.
+fn misplaced_tag(parser: &Parser<'_>) -> Option<ParseErrorKind> {
+    if parser.peek_chars("@html") {
+        Some(ParseErrorKind::InvalidHtmlTagPlacement)
+    } else if parser.peek_chars("@debug") {
+        Some(ParseErrorKind::InvalidDebugTagPlacement)
+    } else if parser.peek_chars("@const") {
+        Some(ParseErrorKind::InvalidConstTagPlacement)
+    } else {
+        None
     }
 }
@@ -1,14 +1,34 @@
 use super::{State, StateTransition};
-use crate::{parser::Parser, state::fragment::FragmentState, syntax_nodes::Text};
+use crate::{
+    html::decode_character_references,
+    parser::{new_span, Parser},
+    state::fragment::FragmentState,
+    syntax_nodes::Text,
+};
 
 #[derive(Debug)]
 pub struct TextState;
 
 impl StateTransition for TextState {
     fn next_state(self, parser: &mut Parser<'_>) -> State {
-        let span = parser.eat_until(|c| matches!(c, '<' | '{'));
+        let span = match parser.escapable_raw_text_end_tag() {
+            Some(end_tag) => {
+                let start = parser.position();
+                let rest = &parser.text()[start..];
+                let end = [rest.find('{'), rest.find(&end_tag)]
+                    .into_iter()
+                    .flatten()
+                    .min()
+                    .unwrap_or(rest.len());
+                parser.eat_to(start + end);
+                new_span(start, start + end)
+            }
+            None => parser.eat_until(|c| matches!(c, '<' | '{')),
+        };
+        let text = parser.text_span(&span);
         parser.push_node(Text {
-            text: parser.text_span(&span).to_string(),
+            text: text.to_string(),
+            data: decode_character_references(text, false),
             span,
         });
 
@@ -0,0 +1,79 @@
+//! Convenience methods for reading common things out of the generated syntax nodes.
+
+use crate::syntax_nodes::{
+    Attribute, AttributeItem, AttributeValue, AttributeValuePart, Directive, DirectiveKind,
+    HtmlAttribute, Slot, StartTag,
+};
+
+impl AttributeValue {
+    /// The decoded text of the value, if it doesn't contain any mustaches.
+    pub fn static_text(&self) -> Option<&str> {
+        match &self.parts[..] {
+            [] => Some(""),
+            [AttributeValuePart::Text(text)] => Some(&text.data),
+            _ => None,
+        }
+    }
+}
+
+impl StartTag {
+    /// Finds a plain HTML attribute, like `name="x"`, by its name.
+    pub fn attribute(&self, name: &str) -> Option<&HtmlAttribute> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match &attribute.attribute_item {
+                AttributeItem::HtmlAttribute(attribute)
+                    if attribute.attribute_name.text == name =>
+                {
+                    Some(attribute)
+                }
+                _ => None,
+            })
+    }
+
+    /// The name of the slot this element is passed to with `slot="name"`, if it's static.
+    pub fn slot_name(&self) -> Option<&str> {
+        self.attribute("slot")?
+            .attribute_initializer
+            .as_ref()?
+            .attribute_value
+            .static_text()
+    }
+
+    /// The `let:` directives that receive props from the slot this element fills.
+    pub fn let_directives(&self) -> impl Iterator<Item = &Directive> {
+        self.attributes
+            .iter()
+            .filter_map(|attribute| match &attribute.attribute_item {
+                AttributeItem::Directive(directive)
+                    if matches!(directive.directive_kind, DirectiveKind::Let(..)) =>
+                {
+                    Some(directive)
+                }
+                _ => None,
+            })
+    }
+}
+
+impl Slot {
+    /// The name of the slot, or `None` for the default slot. Also `None` if the name isn't static,
+    /// which is reported as an error.
+    pub fn name(&self) -> Option<&str> {
+        self.start_tag
+            .attribute("name")?
+            .attribute_initializer
+            .as_ref()?
+            .attribute_value
+            .static_text()
+    }
+
+    /// The attributes passed to the slot's content as props, which is everything but `name`.
+    pub fn props(&self) -> impl Iterator<Item = &Attribute> {
+        self.start_tag.attributes.iter().filter(|attribute| {
+            !matches!(
+                &attribute.attribute_item,
+                AttributeItem::HtmlAttribute(attribute) if attribute.attribute_name.text == "name"
+            )
+        })
+    }
+}
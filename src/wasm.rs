@@ -10,3 +10,12 @@ pub fn parse(source: String) -> JsValue {
 
     JsValue::from_serde(&nodes).unwrap()
 }
+
+/// Like `parse`, but returns the nodes along with the component's `<svelte:options>`.
+#[wasm_bindgen]
+pub fn parse_component(source: String) -> JsValue {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let component = Parser::new(&source, &mut error_reporter).parse_component();
+
+    JsValue::from_serde(&component).unwrap()
+}
@@ -0,0 +1,104 @@
+use proptest::prelude::*;
+use svelters::{error::CollectingErrorReporter, parser::Parser};
+
+/// Pieces of Svelte syntax that are likely to drive the parser into its less travelled paths
+/// when stitched together in unexpected orders.
+const FRAGMENTS: &[&str] = &[
+    "{",
+    "}",
+    "<",
+    ">",
+    "</",
+    "/>",
+    "=",
+    "\"",
+    "'",
+    " ",
+    "\n",
+    "\t",
+    "\r\n",
+    // Whitespace to Unicode but not ASCII, to catch places that disagree about what's whitespace
+    "\u{a0}",
+    "\u{2028}",
+    "\u{3000}",
+    ",",
+    "(",
+    ")",
+    "[",
+    "]",
+    ":",
+    "|",
+    "...",
+    "&amp;",
+    "&#x",
+    "&#12",
+    "<!--",
+    "-->",
+    "#if",
+    "#each",
+    "#await",
+    "#key",
+    "#snippet",
+    ":else",
+    ":else if",
+    ":then",
+    ":catch",
+    "/if",
+    "/each",
+    "/await",
+    "/key",
+    "/snippet",
+    "@html",
+    "@const",
+    "@debug",
+    "@render",
+    "as",
+    "then",
+    "catch",
+    "a",
+    "b.c",
+    "1",
+    "x = 1",
+    "a()",
+    "div",
+    "p",
+    "li",
+    "br",
+    "input",
+    "textarea",
+    "title",
+    "script",
+    "style",
+    "slot",
+    "svelte:options",
+    "svelte:fragment",
+    "svelte:self",
+    "Comp",
+    "slot=",
+    "let:",
+    "on:click",
+    "bind:value",
+    "é",
+    "😀",
+    "中",
+    "\u{301}",
+];
+
+fn parse(source: &str) {
+    let mut error_reporter = CollectingErrorReporter::new();
+    Parser::new(source, &mut error_reporter).parse();
+}
+
+proptest! {
+    #[test]
+    fn never_panics_on_arbitrary_text(source in any::<String>()) {
+        parse(&source);
+    }
+
+    #[test]
+    fn never_panics_on_svelte_like_text(
+        parts in prop::collection::vec(prop::sample::select(FRAGMENTS), 0..32)
+    ) {
+        parse(&parts.concat());
+    }
+}
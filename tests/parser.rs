@@ -1,18 +1,31 @@
 use pretty_assertions::assert_eq;
 use svelters::{
     ecma::{AssignPatProp, Expr, Ident, Lit, MemberExpr, Number, ObjectPat, Pat},
-    error::{CollectingErrorReporter, ParseError, ParseErrorKind},
+    error::{
+        CollectingErrorReporter, Diagnostic, DiagnosticTag, ParseError, ParseErrorKind, SyntaxError,
+    },
+    fix::{apply_fixes, Fix},
+    ignore::svelte_ignore_codes,
+    options::{CustomElement, Namespace, ShadowMode, SvelteOptions},
     parser::{new_span, Parser},
     syntax_nodes::{
-        BlockClose, Comment, CommentText, ConstTag, DebugTag, EachAs, EachBlockOpen, EachIndex,
-        EachKey, IfBlockOpen, InvalidSyntax, KeyBlockOpen, Mustache, MustacheItem, RawMustacheTag,
-        Text,
+        Attribute, AttributeInitializer, AttributeItem, AttributeMustache, AttributeName,
+        AttributeValue, AwaitBlock, AwaitBlockOpen, AwaitShorthand, AwaitThen, BlockBranch,
+        BlockClose, BlockContinuation, BlockOpen, CatchBlock, Comment, CommentText, ConstTag,
+        CssAtRule, CssBlockItem, CssClassSelector, CssCombinator, CssDeclaration,
+        CssGlobalSelector, CssRelativeSelector, CssRule, CssSimpleSelector, CssTypeSelector,
+        DebugTag, Directive, DirectiveKind, DirectiveModifier, DirectiveName, EachAs, EachBlock,
+        EachBlockOpen, EachIndex, EachKey, Element, ElseBlock, ElseIfBlock, EndTag, HtmlAttribute,
+        IfBlock, IfBlockOpen, InvalidSyntax, KeyBlock, KeyBlockOpen, Mustache, MustacheItem, Node,
+        RawMustacheTag, ShorthandAttribute, SpreadAttribute, StartTag, TagName, Text, ThenBlock,
     },
     tokens::{
-        CommentEndToken, CommentStartToken, ConstTagToken, DebugTagToken, HtmlTagToken,
-        IfOpenToken, KeyOpenToken, MustacheCloseToken, MustacheOpenToken, WhitespaceToken,
+        BindToken, CommentEndToken, CommentStartToken, ConstTagToken, DebugTagToken, HtmlTagToken,
+        IfOpenToken, IfToken, KeyOpenToken, MustacheCloseToken, MustacheOpenToken, OnToken,
+        SelfClosingTagCloseToken, TagCloseToken, WhitespaceToken,
     },
 };
+use swc_common::Spanned;
 
 #[test]
 fn fragment() {
@@ -31,6 +44,7 @@ fn text() {
         nodes,
         vec![Text {
             text: "Hello, world!".into(),
+            data: "Hello, world!".into(),
             span: new_span(0, 13),
         }
         .into()]
@@ -102,10 +116,10 @@ fn mustache_expression_missing_close() {
     assert_eq!(nodes, vec![expected_node.into()]);
     assert_eq!(
         error_reporter.parse_errors(),
-        &[ParseError::new(
-            ParseErrorKind::MustacheNotClosed,
-            new_span(5, 6)
-        )]
+        &[
+            ParseError::new(ParseErrorKind::MustacheNotClosed, new_span(5, 6))
+                .with_fix(Fix::insert("Insert missing `}`", 6, "}"))
+        ]
     );
 }
 
@@ -164,10 +178,10 @@ fn mustache_const_tag() {
     assert_eq!(nodes, vec![expected_node.into()]);
     assert_eq!(
         error_reporter.parse_errors(),
-        &[ParseError::new(
-            ParseErrorKind::InvalidConstArgs,
-            new_span(8, 13)
-        )]
+        &[
+            ParseError::new(ParseErrorKind::InvalidConstArgs, new_span(8, 13)),
+            ParseError::new(ParseErrorKind::InvalidConstTagPlacement, new_span(1, 13)),
+        ]
     );
 }
 
@@ -371,8 +385,14 @@ fn key_block_open() {
         span: new_span(0, 12),
     };
 
-    assert_eq!(nodes, vec![expected_node.into()]);
-    assert!(error_reporter.is_empty())
+    assert_eq!(nodes, vec![unclosed_block(expected_node)]);
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[ParseError::new(
+            ParseErrorKind::BlockNotClosed,
+            new_span(1, 11)
+        )]
+    );
 }
 
 #[test]
@@ -404,8 +424,14 @@ fn if_block_open() {
         span: new_span(0, 11),
     };
 
-    assert_eq!(nodes, vec![expected_node.into()]);
-    assert!(error_reporter.is_empty())
+    assert_eq!(nodes, vec![unclosed_block(expected_node)]);
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[ParseError::new(
+            ParseErrorKind::BlockNotClosed,
+            new_span(1, 10)
+        )]
+    );
 }
 
 #[test]
@@ -438,8 +464,14 @@ fn each_block_open() {
         span: new_span(0, 21),
     };
 
-    assert_eq!(nodes, vec![expected_node.into()]);
-    assert!(error_reporter.is_empty())
+    assert_eq!(nodes, vec![unclosed_block(expected_node)]);
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[ParseError::new(
+            ParseErrorKind::BlockNotClosed,
+            new_span(1, 20)
+        )]
+    );
 }
 
 #[test]
@@ -478,8 +510,14 @@ fn each_block_open_index() {
         span: new_span(0, 25),
     };
 
-    assert_eq!(nodes, vec![expected_node.into()]);
-    assert!(error_reporter.is_empty())
+    assert_eq!(nodes, vec![unclosed_block(expected_node)]);
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[ParseError::new(
+            ParseErrorKind::BlockNotClosed,
+            new_span(1, 24)
+        )]
+    );
 }
 
 #[test]
@@ -524,8 +562,14 @@ fn each_block_open_keyed() {
         span: new_span(0, 31),
     };
 
-    assert_eq!(nodes, vec![expected_node.into()]);
-    assert!(error_reporter.is_empty())
+    assert_eq!(nodes, vec![unclosed_block(expected_node)]);
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[ParseError::new(
+            ParseErrorKind::BlockNotClosed,
+            new_span(1, 30)
+        )]
+    );
 }
 
 #[test]
@@ -572,8 +616,14 @@ fn each_block_open_index_keyed() {
         span: new_span(0, 28),
     };
 
-    assert_eq!(nodes, vec![expected_node.into()]);
-    assert!(error_reporter.is_empty())
+    assert_eq!(nodes, vec![unclosed_block(expected_node)]);
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[ParseError::new(
+            ParseErrorKind::BlockNotClosed,
+            new_span(1, 27)
+        )]
+    );
 }
 
 #[test]
@@ -614,13 +664,13 @@ fn each_block_open_invalid_index() {
         span: new_span(0, 30),
     };
 
-    assert_eq!(nodes, vec![expected_node.into()]);
+    assert_eq!(nodes, vec![unclosed_block(expected_node)]);
     assert_eq!(
         error_reporter.parse_errors(),
-        &[ParseError::new(
-            ParseErrorKind::ExpectedEachIndex,
-            new_span(22, 25)
-        )]
+        &[
+            ParseError::new(ParseErrorKind::ExpectedEachIndex, new_span(22, 25)),
+            ParseError::new(ParseErrorKind::BlockNotClosed, new_span(1, 29))
+        ]
     );
 }
 
@@ -665,8 +715,14 @@ fn each_block_destructure() {
         span: new_span(0, 23),
     };
 
-    assert_eq!(nodes, vec![expected_node.into()]);
-    assert!(error_reporter.is_empty())
+    assert_eq!(nodes, vec![unclosed_block(expected_node)]);
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[ParseError::new(
+            ParseErrorKind::BlockNotClosed,
+            new_span(1, 22)
+        )]
+    );
 }
 
 #[test]
@@ -683,7 +739,13 @@ fn key_close() {
     };
 
     assert_eq!(nodes, vec![expected_node.into()]);
-    assert!(error_reporter.is_empty())
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[ParseError::new(
+            ParseErrorKind::UnexpectedBlockClose,
+            new_span(1, 5)
+        )]
+    );
 }
 
 #[test]
@@ -700,7 +762,13 @@ fn if_close() {
     };
 
     assert_eq!(nodes, vec![expected_node.into()]);
-    assert!(error_reporter.is_empty())
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[ParseError::new(
+            ParseErrorKind::UnexpectedBlockClose,
+            new_span(1, 4)
+        )]
+    );
 }
 
 #[test]
@@ -717,7 +785,13 @@ fn await_close() {
     };
 
     assert_eq!(nodes, vec![expected_node.into()]);
-    assert!(error_reporter.is_empty())
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[ParseError::new(
+            ParseErrorKind::UnexpectedBlockClose,
+            new_span(1, 7)
+        )]
+    );
 }
 
 #[test]
@@ -734,7 +808,13 @@ fn each_close() {
     };
 
     assert_eq!(nodes, vec![expected_node.into()]);
-    assert!(error_reporter.is_empty())
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[ParseError::new(
+            ParseErrorKind::UnexpectedBlockClose,
+            new_span(1, 6)
+        )]
+    );
 }
 
 #[test]
@@ -763,3 +843,2137 @@ fn unknown_close() {
         )]
     );
 }
+
+#[test]
+fn element() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let nodes = Parser::new("<div>hello</div>", &mut error_reporter).parse();
+    let expected_node = Element {
+        start_tag: StartTag {
+            tag_open: new_span(0, 1).into(),
+            tag_name: TagName {
+                text: "div".into(),
+                span: new_span(1, 4),
+            },
+            attributes: vec![],
+            trailing_whitespace: None,
+            start_tag_close: Some(TagCloseToken::from(new_span(4, 5)).into()),
+            span: new_span(0, 5),
+        },
+        children: vec![Text {
+            text: "hello".into(),
+            data: "hello".into(),
+            span: new_span(5, 10),
+        }
+        .into()],
+        end_tag: Some(EndTag {
+            end_tag_open: new_span(10, 12).into(),
+            tag_name: TagName {
+                text: "div".into(),
+                span: new_span(12, 15),
+            },
+            whitespace: None,
+            tag_close: Some(new_span(15, 16).into()),
+            span: new_span(10, 16),
+        }),
+        span: new_span(0, 16),
+    };
+
+    assert_eq!(nodes, vec![expected_node.into()]);
+    assert!(error_reporter.is_empty())
+}
+
+#[test]
+fn element_self_closing() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let nodes = Parser::new("<Foo />", &mut error_reporter).parse();
+    let expected_node = Element {
+        start_tag: StartTag {
+            tag_open: new_span(0, 1).into(),
+            tag_name: TagName {
+                text: "Foo".into(),
+                span: new_span(1, 4),
+            },
+            attributes: vec![],
+            trailing_whitespace: Some(new_span(4, 5).into()),
+            start_tag_close: Some(SelfClosingTagCloseToken::from(new_span(5, 7)).into()),
+            span: new_span(0, 7),
+        },
+        children: vec![],
+        end_tag: None,
+        span: new_span(0, 7),
+    };
+
+    assert_eq!(nodes, vec![expected_node.into()]);
+    assert!(error_reporter.is_empty())
+}
+
+#[test]
+fn element_nested() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let nodes = Parser::new("<ul><li>{a}</li ></ul>", &mut error_reporter).parse();
+    let expected_node = Element {
+        start_tag: StartTag {
+            tag_open: new_span(0, 1).into(),
+            tag_name: TagName {
+                text: "ul".into(),
+                span: new_span(1, 3),
+            },
+            attributes: vec![],
+            trailing_whitespace: None,
+            start_tag_close: Some(TagCloseToken::from(new_span(3, 4)).into()),
+            span: new_span(0, 4),
+        },
+        children: vec![Element {
+            start_tag: StartTag {
+                tag_open: new_span(4, 5).into(),
+                tag_name: TagName {
+                    text: "li".into(),
+                    span: new_span(5, 7),
+                },
+                attributes: vec![],
+                trailing_whitespace: None,
+                start_tag_close: Some(TagCloseToken::from(new_span(7, 8)).into()),
+                span: new_span(4, 8),
+            },
+            children: vec![Mustache {
+                mustache_open: new_span(8, 9).into(),
+                leading_whitespace: None,
+                mustache_item: Box::new(Expr::Ident(Ident::new("a".into(), new_span(9, 10))))
+                    .into(),
+                trailing_whitespace: None,
+                mustache_close: Some(new_span(10, 11).into()),
+                span: new_span(8, 11),
+            }
+            .into()],
+            end_tag: Some(EndTag {
+                end_tag_open: new_span(11, 13).into(),
+                tag_name: TagName {
+                    text: "li".into(),
+                    span: new_span(13, 15),
+                },
+                whitespace: Some(new_span(15, 16).into()),
+                tag_close: Some(new_span(16, 17).into()),
+                span: new_span(11, 17),
+            }),
+            span: new_span(4, 17),
+        }
+        .into()],
+        end_tag: Some(EndTag {
+            end_tag_open: new_span(17, 19).into(),
+            tag_name: TagName {
+                text: "ul".into(),
+                span: new_span(19, 21),
+            },
+            whitespace: None,
+            tag_close: Some(new_span(21, 22).into()),
+            span: new_span(17, 22),
+        }),
+        span: new_span(0, 22),
+    };
+
+    assert_eq!(nodes, vec![expected_node.into()]);
+    assert!(error_reporter.is_empty())
+}
+
+#[test]
+fn element_not_closed() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let nodes = Parser::new("<div><span></div>", &mut error_reporter).parse();
+
+    let [Node::Element(div)] = &nodes[..] else {
+        panic!("expected a single element, got {nodes:?}");
+    };
+    assert_eq!(div.span, new_span(0, 17));
+    let [Node::Element(span)] = &div.children[..] else {
+        panic!("expected a single child element, got {:?}", div.children);
+    };
+    assert_eq!(span.span, new_span(5, 11));
+    assert_eq!(span.end_tag, None);
+    // Svelte closes regular elements implicitly, but not components or blocks
+    assert!(error_reporter.is_empty());
+
+    let mut error_reporter = CollectingErrorReporter::new();
+    Parser::new("<div><Span></div><p>{#if a}</p>", &mut error_reporter).parse();
+
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[
+            ParseError::new(ParseErrorKind::ElementNotClosed, new_span(5, 11)),
+            ParseError::new(ParseErrorKind::BlockNotClosed, new_span(21, 26)),
+        ]
+    );
+}
+
+#[test]
+fn unexpected_end_tag() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let nodes = Parser::new("</div>", &mut error_reporter).parse();
+    let expected_node = EndTag {
+        end_tag_open: new_span(0, 2).into(),
+        tag_name: TagName {
+            text: "div".into(),
+            span: new_span(2, 5),
+        },
+        whitespace: None,
+        tag_close: Some(new_span(5, 6).into()),
+        span: new_span(0, 6),
+    };
+
+    assert_eq!(nodes, vec![expected_node.into()]);
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[ParseError::new(
+            ParseErrorKind::UnexpectedEndTag,
+            new_span(0, 6)
+        )]
+    );
+}
+
+fn parse_start_tag(source: &str, error_reporter: &mut CollectingErrorReporter) -> StartTag {
+    let nodes = Parser::new(source, error_reporter).parse();
+    match nodes.into_iter().next() {
+        Some(Node::Element(element)) => element.start_tag,
+        node => panic!("expected an element, got {node:?}"),
+    }
+}
+
+#[test]
+fn attribute_boolean() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let start_tag = parse_start_tag("<input disabled />", &mut error_reporter);
+
+    assert_eq!(
+        start_tag.attributes,
+        vec![Attribute {
+            whitespace: Some(new_span(6, 7).into()),
+            attribute_item: HtmlAttribute {
+                attribute_name: AttributeName {
+                    text: "disabled".into(),
+                    span: new_span(7, 15),
+                },
+                attribute_initializer: None,
+                span: new_span(7, 15),
+            }
+            .into(),
+            span: new_span(6, 15),
+        }]
+    );
+    assert_eq!(start_tag.trailing_whitespace, Some(new_span(15, 16).into()));
+    assert!(error_reporter.is_empty())
+}
+
+#[test]
+fn attribute_quoted_value() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let start_tag = parse_start_tag(r#"<div class = "a {b} c"></div>"#, &mut error_reporter);
+
+    assert_eq!(
+        start_tag.attributes,
+        vec![Attribute {
+            whitespace: Some(new_span(4, 5).into()),
+            attribute_item: HtmlAttribute {
+                attribute_name: AttributeName {
+                    text: "class".into(),
+                    span: new_span(5, 10),
+                },
+                attribute_initializer: Some(AttributeInitializer {
+                    leading_ws: Some(new_span(10, 11).into()),
+                    eq: new_span(11, 12).into(),
+                    trailing_ws: Some(new_span(12, 13).into()),
+                    attribute_value: AttributeValue {
+                        open_quote: Some(new_span(13, 14).into()),
+                        parts: vec![
+                            Text {
+                                text: "a ".into(),
+                                data: "a ".into(),
+                                span: new_span(14, 16),
+                            }
+                            .into(),
+                            AttributeMustache {
+                                mustache_open: new_span(16, 17).into(),
+                                leading_whitespace: None,
+                                expression: Box::new(
+                                    Ident::new("b".into(), new_span(17, 18)).into()
+                                ),
+                                trailing_whitespace: None,
+                                mustache_close: Some(new_span(18, 19).into()),
+                                span: new_span(16, 19),
+                            }
+                            .into(),
+                            Text {
+                                text: " c".into(),
+                                data: " c".into(),
+                                span: new_span(19, 21),
+                            }
+                            .into(),
+                        ],
+                        close_quote: Some(new_span(21, 22).into()),
+                        span: new_span(13, 22),
+                    },
+                    span: new_span(10, 22),
+                }),
+                span: new_span(5, 22),
+            }
+            .into(),
+            span: new_span(4, 22),
+        }]
+    );
+    assert!(error_reporter.is_empty())
+}
+
+#[test]
+fn attribute_unquoted_values() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let start_tag = parse_start_tag("<img width=10 value={x}/>", &mut error_reporter);
+
+    assert_eq!(
+        start_tag.attributes,
+        vec![
+            Attribute {
+                whitespace: Some(new_span(4, 5).into()),
+                attribute_item: HtmlAttribute {
+                    attribute_name: AttributeName {
+                        text: "width".into(),
+                        span: new_span(5, 10),
+                    },
+                    attribute_initializer: Some(AttributeInitializer {
+                        leading_ws: None,
+                        eq: new_span(10, 11).into(),
+                        trailing_ws: None,
+                        attribute_value: AttributeValue {
+                            open_quote: None,
+                            parts: vec![Text {
+                                text: "10".into(),
+                                data: "10".into(),
+                                span: new_span(11, 13),
+                            }
+                            .into()],
+                            close_quote: None,
+                            span: new_span(11, 13),
+                        },
+                        span: new_span(10, 13),
+                    }),
+                    span: new_span(5, 13),
+                }
+                .into(),
+                span: new_span(4, 13),
+            },
+            Attribute {
+                whitespace: Some(new_span(13, 14).into()),
+                attribute_item: HtmlAttribute {
+                    attribute_name: AttributeName {
+                        text: "value".into(),
+                        span: new_span(14, 19),
+                    },
+                    attribute_initializer: Some(AttributeInitializer {
+                        leading_ws: None,
+                        eq: new_span(19, 20).into(),
+                        trailing_ws: None,
+                        attribute_value: AttributeValue {
+                            open_quote: None,
+                            parts: vec![AttributeMustache {
+                                mustache_open: new_span(20, 21).into(),
+                                leading_whitespace: None,
+                                expression: Box::new(
+                                    Ident::new("x".into(), new_span(21, 22)).into()
+                                ),
+                                trailing_whitespace: None,
+                                mustache_close: Some(new_span(22, 23).into()),
+                                span: new_span(20, 23),
+                            }
+                            .into()],
+                            close_quote: None,
+                            span: new_span(20, 23),
+                        },
+                        span: new_span(19, 23),
+                    }),
+                    span: new_span(14, 23),
+                }
+                .into(),
+                span: new_span(13, 23),
+            },
+        ]
+    );
+    assert_eq!(
+        start_tag.start_tag_close,
+        Some(SelfClosingTagCloseToken::from(new_span(23, 25)).into())
+    );
+    assert!(error_reporter.is_empty())
+}
+
+#[test]
+fn attribute_shorthand() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let start_tag = parse_start_tag("<Foo {name} {a.b}/>", &mut error_reporter);
+
+    assert_eq!(
+        start_tag.attributes[0],
+        Attribute {
+            whitespace: Some(new_span(4, 5).into()),
+            attribute_item: ShorthandAttribute {
+                attribute_mustache: AttributeMustache {
+                    mustache_open: new_span(5, 6).into(),
+                    leading_whitespace: None,
+                    expression: Box::new(Ident::new("name".into(), new_span(6, 10)).into()),
+                    trailing_whitespace: None,
+                    mustache_close: Some(new_span(10, 11).into()),
+                    span: new_span(5, 11),
+                },
+                span: new_span(5, 11),
+            }
+            .into(),
+            span: new_span(4, 11),
+        }
+    );
+    assert_eq!(start_tag.attributes.len(), 2);
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[ParseError::new(
+            ParseErrorKind::InvalidShorthandAttribute,
+            new_span(13, 16)
+        )]
+    );
+}
+
+#[test]
+fn attribute_value_not_closed() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let start_tag = parse_start_tag("<a href='/home>", &mut error_reporter);
+
+    let [Attribute {
+        attribute_item: AttributeItem::HtmlAttribute(attribute),
+        ..
+    }] = &start_tag.attributes[..]
+    else {
+        panic!(
+            "expected a single attribute, got {:?}",
+            start_tag.attributes
+        );
+    };
+    let value = &attribute
+        .attribute_initializer
+        .as_ref()
+        .unwrap()
+        .attribute_value;
+    assert_eq!(value.open_quote, Some(new_span(8, 9).into()));
+    assert_eq!(value.close_quote, None);
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[
+            ParseError::new(
+                ParseErrorKind::AttributeValueNotClosed('\''),
+                new_span(14, 15)
+            ),
+            ParseError::new(ParseErrorKind::TagNotClosed, new_span(14, 15)),
+            ParseError::new(ParseErrorKind::ElementNotClosed, new_span(0, 15)),
+        ]
+    );
+}
+
+#[test]
+fn directive() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let start_tag = parse_start_tag(
+        "<button on:click|preventDefault|once={handler} />",
+        &mut error_reporter,
+    );
+
+    assert_eq!(
+        start_tag.attributes,
+        vec![Attribute {
+            whitespace: Some(new_span(7, 8).into()),
+            attribute_item: Directive {
+                directive_kind: OnToken::from(new_span(8, 10)).into(),
+                colon: new_span(10, 11).into(),
+                directive_name: DirectiveName {
+                    text: "click".into(),
+                    span: new_span(11, 16),
+                },
+                modifiers: vec![
+                    DirectiveModifier {
+                        pipe: new_span(16, 17).into(),
+                        text: "preventDefault".into(),
+                        span: new_span(16, 31),
+                    },
+                    DirectiveModifier {
+                        pipe: new_span(31, 32).into(),
+                        text: "once".into(),
+                        span: new_span(31, 36),
+                    },
+                ],
+                attribute_initializer: Some(AttributeInitializer {
+                    leading_ws: None,
+                    eq: new_span(36, 37).into(),
+                    trailing_ws: None,
+                    attribute_value: AttributeValue {
+                        open_quote: None,
+                        parts: vec![AttributeMustache {
+                            mustache_open: new_span(37, 38).into(),
+                            leading_whitespace: None,
+                            expression: Box::new(
+                                Ident::new("handler".into(), new_span(38, 45)).into()
+                            ),
+                            trailing_whitespace: None,
+                            mustache_close: Some(new_span(45, 46).into()),
+                            span: new_span(37, 46),
+                        }
+                        .into()],
+                        close_quote: None,
+                        span: new_span(37, 46),
+                    },
+                    span: new_span(36, 46),
+                }),
+                span: new_span(8, 46),
+            }
+            .into(),
+            span: new_span(7, 46),
+        }]
+    );
+    assert!(error_reporter.is_empty())
+}
+
+#[test]
+fn directive_without_value() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let start_tag = parse_start_tag("<input bind:value />", &mut error_reporter);
+
+    assert_eq!(
+        start_tag.attributes,
+        vec![Attribute {
+            whitespace: Some(new_span(6, 7).into()),
+            attribute_item: Directive {
+                directive_kind: BindToken::from(new_span(7, 11)).into(),
+                colon: new_span(11, 12).into(),
+                directive_name: DirectiveName {
+                    text: "value".into(),
+                    span: new_span(12, 17),
+                },
+                modifiers: vec![],
+                attribute_initializer: None,
+                span: new_span(7, 17),
+            }
+            .into(),
+            span: new_span(6, 17),
+        }]
+    );
+    assert!(error_reporter.is_empty())
+}
+
+#[test]
+fn style_directive_modifiers() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let start_tag = parse_start_tag(
+        r#"<p style:color|important={c} style:width|once="1" />"#,
+        &mut error_reporter,
+    );
+
+    let AttributeItem::Directive(directive) = &start_tag.attributes[0].attribute_item else {
+        panic!("expected directive, got {:?}", start_tag.attributes[0]);
+    };
+    assert!(matches!(directive.directive_kind, DirectiveKind::Style(..)));
+    assert_eq!(directive.modifiers.len(), 1);
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[ParseError::new(
+            ParseErrorKind::IllegalDirectiveModifier,
+            new_span(40, 45)
+        )]
+    );
+}
+
+#[test]
+fn directive_errors() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let start_tag = parse_start_tag(
+        r##"<svg foo:bar xlink:href="#a" class:active|once="yes" />"##,
+        &mut error_reporter,
+    );
+
+    assert!(matches!(
+        start_tag.attributes[0].attribute_item,
+        AttributeItem::HtmlAttribute(..)
+    ));
+    assert!(matches!(
+        start_tag.attributes[1].attribute_item,
+        AttributeItem::HtmlAttribute(..)
+    ));
+    assert!(matches!(
+        start_tag.attributes[2].attribute_item,
+        AttributeItem::Directive(Directive {
+            directive_kind: DirectiveKind::Class(..),
+            ..
+        })
+    ));
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[
+            ParseError::new(ParseErrorKind::UnknownDirectiveType, new_span(5, 8)),
+            ParseError::new(ParseErrorKind::IllegalDirectiveModifier, new_span(41, 46)),
+            ParseError::new(ParseErrorKind::InvalidDirectiveValue, new_span(47, 52)),
+        ]
+    );
+}
+
+#[test]
+fn spread_attribute() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let start_tag = parse_start_tag("<Foo { ...props }/>", &mut error_reporter);
+
+    assert_eq!(
+        start_tag.attributes,
+        vec![Attribute {
+            whitespace: Some(new_span(4, 5).into()),
+            attribute_item: SpreadAttribute {
+                mustache_open: new_span(5, 6).into(),
+                leading_whitespace: Some(new_span(6, 7).into()),
+                spread: new_span(7, 10).into(),
+                expression: Box::new(Ident::new("props".into(), new_span(10, 15)).into()),
+                trailing_whitespace: Some(new_span(15, 16).into()),
+                mustache_close: Some(new_span(16, 17).into()),
+                span: new_span(5, 17),
+            }
+            .into(),
+            span: new_span(4, 17),
+        }]
+    );
+    assert!(error_reporter.is_empty())
+}
+
+#[test]
+fn spread_attribute_unicode_whitespace() {
+    // Only ASCII whitespace is allowed before the `...`, like everywhere else in a mustache
+    let mut error_reporter = CollectingErrorReporter::new();
+    let start_tag = parse_start_tag("<div {\u{a0}...x}></div>", &mut error_reporter);
+
+    assert!(matches!(
+        start_tag.attributes[0].attribute_item,
+        AttributeItem::ShorthandAttribute(..)
+    ));
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[ParseError::new(
+            ParseErrorKind::InvalidJavaScript(SyntaxError::TS1109.into()),
+            new_span(8, 11)
+        )]
+    );
+}
+
+#[test]
+fn spread_in_text() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let nodes = Parser::new("{...props}", &mut error_reporter).parse();
+    let expected_node = Mustache {
+        mustache_open: new_span(0, 1).into(),
+        leading_whitespace: None,
+        mustache_item: InvalidSyntax {
+            text: "...props".into(),
+            span: new_span(1, 9),
+        }
+        .into(),
+        trailing_whitespace: None,
+        mustache_close: Some(new_span(9, 10).into()),
+        span: new_span(0, 10),
+    };
+
+    assert_eq!(nodes, vec![expected_node.into()]);
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[ParseError::new(
+            ParseErrorKind::UnexpectedSpread,
+            new_span(1, 9)
+        )]
+    );
+}
+
+#[test]
+fn instance_script() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let nodes = Parser::new("<script>let a = 1;</script>\n{a}", &mut error_reporter).parse();
+
+    let [Node::InstanceScript(script), Node::Text(..), Node::Mustache(..)] = &nodes[..] else {
+        panic!("expected instance script, text and mustache, got {nodes:?}");
+    };
+    assert_eq!(script.start_tag.span, new_span(0, 8));
+    assert_eq!(script.module.span, new_span(8, 18));
+    assert_eq!(script.module.body.len(), 1);
+    assert_eq!(script.module.body[0].span(), new_span(8, 18));
+    assert_eq!(
+        script.end_tag.as_ref().map(|end_tag| end_tag.span),
+        Some(new_span(18, 27))
+    );
+    assert_eq!(script.span, new_span(0, 27));
+    assert!(error_reporter.is_empty())
+}
+
+#[test]
+fn module_script() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let nodes = Parser::new(
+        r#"<script context="module">export const x = 1;</script><script module></script>"#,
+        &mut error_reporter,
+    )
+    .parse();
+
+    let [Node::ModuleScript(script), Node::ModuleScript(..)] = &nodes[..] else {
+        panic!("expected two module scripts, got {nodes:?}");
+    };
+    assert_eq!(script.module.span, new_span(25, 44));
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[ParseError::new(
+            ParseErrorKind::DuplicateModuleScript,
+            new_span(53, 68)
+        )]
+    );
+}
+
+#[test]
+fn duplicate_instance_script() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let nodes = Parser::new("<script></script><script>", &mut error_reporter).parse();
+
+    let [Node::InstanceScript(..), Node::InstanceScript(script)] = &nodes[..] else {
+        panic!("expected two instance scripts, got {nodes:?}");
+    };
+    assert_eq!(script.end_tag, None);
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[
+            ParseError::new(ParseErrorKind::ElementNotClosed, new_span(17, 25)),
+            ParseError::new(ParseErrorKind::DuplicateInstanceScript, new_span(17, 25)),
+        ]
+    );
+}
+
+#[test]
+fn typescript_expression() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let nodes = Parser::new(
+        "{value as string}<script lang=\"ts\">let a: number = 1;</script>",
+        &mut error_reporter,
+    )
+    .parse();
+
+    let [Node::Mustache(mustache), Node::InstanceScript(script)] = &nodes[..] else {
+        panic!("expected mustache and instance script, got {nodes:?}");
+    };
+    assert!(matches!(
+        &mustache.mustache_item,
+        MustacheItem::Expression(expression) if expression.is_ts_as()
+    ));
+    assert_eq!(script.module.body.len(), 1);
+    assert!(error_reporter.is_empty())
+}
+
+#[test]
+fn typescript_lang_attribute() {
+    for source in [
+        "{value as string}<script lang='ts'></script>",
+        "{value as string}<script lang=typescript></script>",
+        "{value as string}<script data-lang=\"ts\" lang = \"ts\"></script>",
+    ] {
+        let mut error_reporter = CollectingErrorReporter::new();
+        let nodes = Parser::new(source, &mut error_reporter).parse();
+
+        let Node::Mustache(mustache) = &nodes[0] else {
+            panic!("expected mustache, got {nodes:?}");
+        };
+        assert!(
+            matches!(
+                &mustache.mustache_item,
+                MustacheItem::Expression(expression) if expression.is_ts_as()
+            ),
+            "expected typescript expression for {source:?}"
+        );
+        assert!(
+            error_reporter.is_empty(),
+            "unexpected errors for {source:?}"
+        );
+    }
+
+    for source in [
+        "{value as string}<script data-lang=\"ts\"></script>",
+        "{value as string}<script title=\"lang=ts\"></script>",
+        "{value as string}<scripts lang=\"ts\"></scripts>",
+    ] {
+        let mut error_reporter = CollectingErrorReporter::new();
+        Parser::new(source, &mut error_reporter).parse();
+
+        assert!(
+            error_reporter
+                .parse_errors()
+                .iter()
+                .any(|error| error.kind == ParseErrorKind::MustacheNotClosed),
+            "expected `as` not to be parsed for {source:?}"
+        );
+    }
+}
+
+fn parse_typescript_each(
+    source: &str,
+    error_reporter: &mut CollectingErrorReporter,
+) -> EachBlockOpen {
+    let nodes = Parser::new(source, error_reporter)
+        .with_typescript(true)
+        .parse();
+    match nodes.into_iter().next() {
+        Some(Node::EachBlock(EachBlock {
+            open:
+                Mustache {
+                    mustache_item: MustacheItem::BlockOpen(BlockOpen::EachBlockOpen(each)),
+                    ..
+                },
+            ..
+        })) => each,
+        node => panic!("expected each block open, got {node:?}"),
+    }
+}
+
+#[test]
+fn typescript_each_context() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let each = parse_typescript_each("{#each items as item: Item}{/each}", &mut error_reporter);
+
+    let Pat::Ident(context) = &each.context else {
+        panic!("expected identifier context, got {:?}", each.context);
+    };
+    assert_eq!(context.id.sym.as_ref(), "item");
+    assert_eq!(
+        context.type_ann.as_ref().map(|type_ann| type_ann.span),
+        Some(new_span(20, 26))
+    );
+    assert_eq!(each.span, new_span(1, 26));
+    assert!(error_reporter.is_empty())
+}
+
+#[test]
+fn typescript_each_context_index() {
+    // In TypeScript `items as item, i` parses as a sequence expression starting with an `as`
+    let mut error_reporter = CollectingErrorReporter::new();
+    let each = parse_typescript_each("{#each items as item, i}{/each}", &mut error_reporter);
+
+    assert_eq!(each.expression.span(), new_span(7, 12));
+    assert!(matches!(&each.context, Pat::Ident(context) if &*context.id.sym == "item"));
+    let index = each.index.expect("each block should have an index");
+    assert_eq!(index.identifier.sym.as_ref(), "i");
+    assert_eq!(index.span, new_span(20, 23));
+    assert_eq!(each.span, new_span(1, 23));
+    assert!(error_reporter.is_empty())
+}
+
+#[test]
+fn typescript_each_context_key() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let each = parse_typescript_each(
+        "{#each items as item, i (item.id)}{/each}",
+        &mut error_reporter,
+    );
+
+    assert_eq!(each.expression.span(), new_span(7, 12));
+    assert!(matches!(&each.context, Pat::Ident(context) if &*context.id.sym == "item"));
+    assert_eq!(
+        each.index.map(|index| index.identifier.sym),
+        Some("i".into())
+    );
+    let key = each.key.expect("each block should have a key");
+    assert_eq!(key.expression.span(), new_span(25, 32));
+    assert_eq!(each.span, new_span(1, 33));
+    assert!(error_reporter.is_empty())
+}
+
+#[test]
+fn typescript_each_binary_expression() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let each = parse_typescript_each("{#each a || b as item}{/each}", &mut error_reporter);
+
+    let Expr::Bin(expression) = &*each.expression else {
+        panic!("expected binary expression, got {:?}", each.expression);
+    };
+    assert_eq!(expression.span, new_span(7, 13));
+    assert_eq!(expression.right.span(), new_span(12, 13));
+    assert!(matches!(&each.context, Pat::Ident(context) if &*context.id.sym == "item"));
+    assert_eq!(each.span, new_span(1, 21));
+    assert!(error_reporter.is_empty());
+
+    let mut error_reporter = CollectingErrorReporter::new();
+    let each = parse_typescript_each("{#each items ?? [] as item, i}{/each}", &mut error_reporter);
+
+    let Expr::Bin(expression) = &*each.expression else {
+        panic!("expected binary expression, got {:?}", each.expression);
+    };
+    assert_eq!(expression.span, new_span(7, 18));
+    assert!(expression.right.is_array());
+    assert!(matches!(&each.context, Pat::Ident(context) if &*context.id.sym == "item"));
+    assert_eq!(
+        each.index.map(|index| index.identifier.sym),
+        Some("i".into())
+    );
+    assert_eq!(each.span, new_span(1, 29));
+    assert!(error_reporter.is_empty())
+}
+
+#[test]
+fn style() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let nodes = Parser::new(
+        "<style>\n  .a > p { color: red; }\n</style>",
+        &mut error_reporter,
+    )
+    .parse();
+
+    let [Node::Style(style)] = &nodes[..] else {
+        panic!("expected style, got {nodes:?}");
+    };
+    assert_eq!(style.style_sheet.span, new_span(7, 33));
+    let [CssRule::CssStyleRule(rule)] = &style.style_sheet.rules[..] else {
+        panic!("expected a single rule, got {:?}", style.style_sheet.rules);
+    };
+    assert_eq!(rule.span, new_span(10, 32));
+    assert_eq!(
+        rule.css_selector_list.selectors[0].relative_selectors,
+        vec![
+            CssRelativeSelector {
+                css_combinator: None,
+                selectors: vec![CssClassSelector {
+                    name: "a".into(),
+                    span: new_span(10, 12),
+                }
+                .into()],
+                span: new_span(10, 12),
+            },
+            CssRelativeSelector {
+                css_combinator: Some(CssCombinator {
+                    text: ">".into(),
+                    span: new_span(13, 14),
+                }),
+                selectors: vec![CssTypeSelector {
+                    name: "p".into(),
+                    span: new_span(15, 16),
+                }
+                .into()],
+                span: new_span(13, 16),
+            },
+        ]
+    );
+    assert_eq!(
+        rule.css_block.children,
+        vec![CssDeclaration {
+            property: "color".into(),
+            value: "red".into(),
+            span: new_span(19, 29),
+        }
+        .into()]
+    );
+    assert_eq!(style.span, new_span(0, 41));
+    assert!(error_reporter.is_empty())
+}
+
+#[test]
+fn style_global() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let nodes = Parser::new(
+        "<style>:global(.a) b {} :global { c {} }</style>",
+        &mut error_reporter,
+    )
+    .parse();
+
+    let [Node::Style(style)] = &nodes[..] else {
+        panic!("expected style, got {nodes:?}");
+    };
+    let [CssRule::CssStyleRule(first), CssRule::CssStyleRule(second)] =
+        &style.style_sheet.rules[..]
+    else {
+        panic!("expected two rules, got {:?}", style.style_sheet.rules);
+    };
+
+    let [CssSimpleSelector::CssGlobalSelector(global)] =
+        &first.css_selector_list.selectors[0].relative_selectors[0].selectors[..]
+    else {
+        panic!("expected :global(...), got {first:?}");
+    };
+    assert_eq!(global.span, new_span(7, 18));
+    let args = global.args.as_ref().unwrap();
+    assert_eq!(
+        args.selectors[0].relative_selectors[0].selectors,
+        vec![CssClassSelector {
+            name: "a".into(),
+            span: new_span(15, 17),
+        }
+        .into()]
+    );
+
+    assert_eq!(
+        second.css_selector_list.selectors[0].relative_selectors[0].selectors,
+        vec![CssGlobalSelector {
+            args: None,
+            span: new_span(24, 31),
+        }
+        .into()]
+    );
+    assert!(matches!(
+        &second.css_block.children[..],
+        [CssBlockItem::CssStyleRule(..)]
+    ));
+    assert!(error_reporter.is_empty())
+}
+
+#[test]
+fn style_at_rules() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let nodes = Parser::new(
+        "<style>@import 'a.css';@keyframes k { 0% { a: b } to { a: c } }\n\
+         li:nth-child(2n + 1 of .x) { background: url(x;y.png) }</style>",
+        &mut error_reporter,
+    )
+    .parse();
+
+    let [Node::Style(style)] = &nodes[..] else {
+        panic!("expected style, got {nodes:?}");
+    };
+    let [CssRule::CssAtRule(import), CssRule::CssAtRule(keyframes), CssRule::CssStyleRule(rule)] =
+        &style.style_sheet.rules[..]
+    else {
+        panic!(
+            "expected two at-rules and a rule, got {:?}",
+            style.style_sheet.rules
+        );
+    };
+    assert_eq!(
+        import,
+        &CssAtRule {
+            name: "import".into(),
+            prelude: "'a.css'".into(),
+            css_block: None,
+            span: new_span(7, 23),
+        }
+    );
+    assert_eq!(keyframes.name, "keyframes");
+    assert_eq!(keyframes.prelude, "k");
+    let children = &keyframes.css_block.as_ref().unwrap().children;
+    let [CssBlockItem::CssStyleRule(from), CssBlockItem::CssStyleRule(..)] = &children[..] else {
+        panic!("expected two keyframes, got {children:?}");
+    };
+    assert!(matches!(
+        &from.css_selector_list.selectors[0].relative_selectors[0].selectors[..],
+        [CssSimpleSelector::CssPercentage(percentage)] if percentage.value == "0%"
+    ));
+
+    let [CssSimpleSelector::CssTypeSelector(..), CssSimpleSelector::CssPseudoClassSelector(nth)] =
+        &rule.css_selector_list.selectors[0].relative_selectors[0].selectors[..]
+    else {
+        panic!("expected li:nth-child(...), got {rule:?}");
+    };
+    let args = &nth.args.as_ref().unwrap().selectors[0].relative_selectors[0].selectors;
+    assert!(matches!(
+        &args[..],
+        [CssSimpleSelector::CssNth(nth), CssSimpleSelector::CssClassSelector(..)]
+            if nth.value == "2n + 1 of "
+    ));
+    assert!(matches!(
+        &rule.css_block.children[..],
+        [CssBlockItem::CssDeclaration(declaration)] if declaration.value == "url(x;y.png)"
+    ));
+    assert!(error_reporter.is_empty())
+}
+
+#[test]
+fn style_errors() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let nodes = Parser::new(
+        "<style>a { color: } b c {}</style><style>{</style>",
+        &mut error_reporter,
+    )
+    .parse();
+
+    let [Node::Style(style), Node::Style(..)] = &nodes[..] else {
+        panic!("expected two styles, got {nodes:?}");
+    };
+    assert_eq!(style.style_sheet.rules.len(), 2);
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[
+            ParseError::new(ParseErrorKind::EmptyCssDeclaration, new_span(11, 17)),
+            ParseError::new(ParseErrorKind::ExpectedCssIdentifier, new_span(41, 42)),
+            ParseError::new(ParseErrorKind::ExpectedCssToken('}'), new_span(42, 42)),
+            ParseError::new(ParseErrorKind::DuplicateStyle, new_span(34, 41)),
+        ]
+    );
+}
+
+#[test]
+fn await_block() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let nodes = Parser::new(
+        "{#await promise}{:then { value }}{:catch}{/await}",
+        &mut error_reporter,
+    )
+    .parse();
+
+    let [Node::AwaitBlock(AwaitBlock {
+        open,
+        branches,
+        close: Some(..),
+        ..
+    })] = &nodes[..]
+    else {
+        panic!("expected await block, got {nodes:?}");
+    };
+    let [BlockBranch { mustache: then, .. }, BlockBranch {
+        mustache: catch, ..
+    }] = &branches[..]
+    else {
+        panic!("expected then and catch branches, got {branches:?}");
+    };
+    assert_eq!(
+        open.mustache_item,
+        MustacheItem::BlockOpen(
+            AwaitBlockOpen {
+                await_open: new_span(1, 7).into(),
+                whitespace: new_span(7, 8).into(),
+                expression: Box::new(Expr::Ident(Ident::new("promise".into(), new_span(8, 15)))),
+                await_shorthand: None,
+                span: new_span(1, 15),
+            }
+            .into()
+        )
+    );
+
+    let MustacheItem::BlockContinuation(BlockContinuation::ThenBlock(ThenBlock {
+        then_branch,
+        whitespace,
+        value: Some(Pat::Object(value)),
+        ..
+    })) = &then.mustache_item
+    else {
+        panic!("expected {{:then}} with a value, got {then:?}");
+    };
+    assert_eq!(then_branch.span, new_span(17, 22));
+    assert_eq!(
+        whitespace.as_ref().map(|ws| ws.span),
+        Some(new_span(22, 23))
+    );
+    assert_eq!(value.span, new_span(23, 32));
+
+    assert_eq!(
+        catch.mustache_item,
+        MustacheItem::BlockContinuation(
+            CatchBlock {
+                catch_branch: new_span(34, 40).into(),
+                whitespace: None,
+                error: None,
+                span: new_span(34, 40),
+            }
+            .into()
+        )
+    );
+    assert!(error_reporter.is_empty())
+}
+
+#[test]
+fn await_block_shorthand() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let nodes = Parser::new(
+        "{#await promise then [a, b]}{/await}{#await promise catch error}{/await}",
+        &mut error_reporter,
+    )
+    .parse();
+
+    let [Node::AwaitBlock(AwaitBlock { open: then, .. }), Node::AwaitBlock(AwaitBlock { open: catch, .. })] =
+        &nodes[..]
+    else {
+        panic!("expected two await blocks, got {nodes:?}");
+    };
+    let MustacheItem::BlockOpen(BlockOpen::AwaitBlockOpen(AwaitBlockOpen {
+        expression,
+        await_shorthand:
+            Some(AwaitShorthand::AwaitThen(AwaitThen {
+                value: Some(Pat::Array(value)),
+                span,
+                ..
+            })),
+        ..
+    })) = &then.mustache_item
+    else {
+        panic!("expected {{#await ... then}}, got {then:?}");
+    };
+    assert_eq!(expression.span(), new_span(8, 15));
+    assert_eq!(value.span, new_span(21, 27));
+    assert_eq!(*span, new_span(15, 27));
+
+    let MustacheItem::BlockOpen(BlockOpen::AwaitBlockOpen(AwaitBlockOpen {
+        await_shorthand: Some(AwaitShorthand::AwaitCatch(catch)),
+        ..
+    })) = &catch.mustache_item
+    else {
+        panic!("expected {{#await ... catch}}, got {catch:?}");
+    };
+    assert_eq!(
+        catch.error,
+        Some(Pat::Ident(
+            Ident::new("error".into(), new_span(58, 63)).into()
+        ))
+    );
+    assert!(error_reporter.is_empty())
+}
+
+#[test]
+fn unknown_block_continuation() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let nodes = Parser::new("{:finally}", &mut error_reporter).parse();
+
+    let [Node::Mustache(mustache)] = &nodes[..] else {
+        panic!("expected mustache, got {nodes:?}");
+    };
+    assert_eq!(
+        mustache.mustache_item,
+        MustacheItem::BlockContinuation(
+            InvalidSyntax {
+                text: ":finally".into(),
+                span: new_span(1, 9),
+            }
+            .into()
+        )
+    );
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[ParseError::new(
+            ParseErrorKind::UnknownBlockContinuation,
+            new_span(1, 9)
+        )]
+    );
+}
+
+#[test]
+fn else_blocks() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let nodes = Parser::new(
+        "{#if a}{:else if b}{:else}{/if}{#each items as item}{:else}{/each}",
+        &mut error_reporter,
+    )
+    .parse();
+
+    let [Node::IfBlock(if_block), Node::EachBlock(each_block)] = &nodes[..] else {
+        panic!("expected if and each blocks, got {nodes:?}");
+    };
+    let [BlockBranch {
+        mustache: else_if, ..
+    }, BlockBranch {
+        mustache: else_, ..
+    }] = &if_block.branches[..]
+    else {
+        panic!("expected two branches, got {:?}", if_block.branches);
+    };
+    assert_eq!(
+        else_if.mustache_item,
+        MustacheItem::BlockContinuation(
+            ElseIfBlock {
+                else_branch: new_span(8, 13).into(),
+                leading_ws: new_span(13, 14).into(),
+                if_: IfToken {
+                    span: new_span(14, 16),
+                },
+                trailing_ws: new_span(16, 17).into(),
+                expression: Box::new(Expr::Ident(Ident::new("b".into(), new_span(17, 18)))),
+                span: new_span(8, 18),
+            }
+            .into()
+        )
+    );
+    assert_eq!(
+        else_.mustache_item,
+        MustacheItem::BlockContinuation(
+            ElseBlock {
+                else_branch: new_span(20, 25).into(),
+                span: new_span(20, 25),
+            }
+            .into()
+        )
+    );
+    assert_eq!(each_block.branches.len(), 1);
+    assert!(error_reporter.is_empty())
+}
+
+#[test]
+fn block_continuation_errors() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    Parser::new(
+        "{:else}{#await p}{:else}{/await}{#if a}{:else}{:else if b}{/if}{#each a as b}{:then}{/each}",
+        &mut error_reporter,
+    )
+    .parse();
+
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[
+            ParseError::new(
+                ParseErrorKind::BlockContinuationOutsideBlock,
+                new_span(1, 6)
+            ),
+            ParseError::new(ParseErrorKind::InvalidBlockContinuation, new_span(18, 23)),
+            ParseError::new(ParseErrorKind::InvalidBlockContinuation, new_span(47, 57)),
+            ParseError::new(ParseErrorKind::InvalidBlockContinuation, new_span(78, 83)),
+        ]
+    );
+}
+
+/// The node for a block opened by `open` that's never closed.
+fn unclosed_block(open: Mustache) -> Node {
+    let span = open.span;
+    let MustacheItem::BlockOpen(block_open) = &open.mustache_item else {
+        panic!("expected block open, got {open:?}");
+    };
+    match block_open {
+        BlockOpen::IfBlockOpen(..) => IfBlock {
+            open,
+            children: vec![],
+            branches: vec![],
+            close: None,
+            span,
+        }
+        .into(),
+        BlockOpen::EachBlockOpen(..) => EachBlock {
+            open,
+            children: vec![],
+            branches: vec![],
+            close: None,
+            span,
+        }
+        .into(),
+        BlockOpen::KeyBlockOpen(..) => KeyBlock {
+            open,
+            children: vec![],
+            close: None,
+            span,
+        }
+        .into(),
+        _ => panic!("unexpected block open {block_open:?}"),
+    }
+}
+
+#[test]
+fn block_tree() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let nodes = Parser::new(
+        "{#if a}<p>{#key b}x{/key}</p>{:else}y{/if}",
+        &mut error_reporter,
+    )
+    .parse();
+
+    let [Node::IfBlock(if_block)] = &nodes[..] else {
+        panic!("expected if block, got {nodes:?}");
+    };
+    assert_eq!(if_block.span, new_span(0, 42));
+    let [Node::Element(element)] = &if_block.children[..] else {
+        panic!("expected element, got {:?}", if_block.children);
+    };
+    let [Node::KeyBlock(key_block)] = &element.children[..] else {
+        panic!("expected key block, got {:?}", element.children);
+    };
+    assert_eq!(key_block.span, new_span(10, 25));
+    assert_eq!(key_block.children.len(), 1);
+
+    let [branch] = &if_block.branches[..] else {
+        panic!("expected else branch, got {:?}", if_block.branches);
+    };
+    assert_eq!(branch.span, new_span(29, 37));
+    assert_eq!(
+        branch.children,
+        vec![Text {
+            text: "y".into(),
+            data: "y".into(),
+            span: new_span(36, 37),
+        }
+        .into()]
+    );
+    assert_eq!(
+        if_block.close.as_ref().map(|close| close.span),
+        Some(new_span(37, 42))
+    );
+    assert!(error_reporter.is_empty())
+}
+
+#[test]
+fn mismatched_block_close() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let nodes = Parser::new("{#if a}{#each b as c}<p>{/if}{/each}", &mut error_reporter).parse();
+
+    let [Node::IfBlock(if_block), Node::Mustache(orphan)] = &nodes[..] else {
+        panic!("expected if block and a stray close, got {nodes:?}");
+    };
+    assert!(if_block.close.is_some());
+    let [Node::EachBlock(each_block)] = &if_block.children[..] else {
+        panic!("expected each block, got {:?}", if_block.children);
+    };
+    assert!(each_block.close.is_none());
+    assert!(matches!(&each_block.children[..], [Node::Element(..)]));
+    assert_eq!(orphan.span, new_span(29, 36));
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[
+            ParseError::new(ParseErrorKind::ElementNotClosed, new_span(21, 24)),
+            ParseError::new(ParseErrorKind::BlockNotClosed, new_span(8, 20)),
+            ParseError::new(ParseErrorKind::UnexpectedBlockClose, new_span(30, 35)),
+        ]
+    );
+}
+
+#[test]
+fn special_elements() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let nodes = Parser::new(
+        "<svelte:window on:resize={f} /><svelte:head><title>x</title></svelte:head>\
+         <svelte:element this={tag}>y</svelte:element><svelte:options />",
+        &mut error_reporter,
+    )
+    .parse();
+
+    assert!(
+        matches!(
+            &nodes[..],
+            [
+                Node::SvelteWindow(..),
+                Node::SvelteHead(..),
+                Node::SvelteElement(..),
+                Node::SvelteOptionsElement(..)
+            ]
+        ),
+        "expected special elements, got {nodes:?}"
+    );
+    assert!(error_reporter.is_empty())
+}
+
+#[test]
+fn special_element_errors() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    Parser::new(
+        "<svelte:component /><svelte:body>x</svelte:body><div><svelte:window /></div>\
+         <svelte:window /><svelte:window /><svelte:foo />",
+        &mut error_reporter,
+    )
+    .parse();
+
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[
+            ParseError::new(ParseErrorKind::MissingThisAttribute, new_span(0, 20)),
+            ParseError::new(ParseErrorKind::InvalidSvelteMetaContent, new_span(20, 33)),
+            ParseError::new(ParseErrorKind::InvalidSvelteMetaPlacement, new_span(53, 70)),
+            ParseError::new(ParseErrorKind::DuplicateSvelteMeta, new_span(93, 110)),
+            ParseError::new(ParseErrorKind::UnknownSvelteTag, new_span(111, 121)),
+        ]
+    );
+}
+
+#[test]
+fn svelte_options() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let component = Parser::new(
+        r#"<svelte:options immutable accessors={false} namespace="svg" customElement="my-tag" runes />"#,
+        &mut error_reporter,
+    )
+    .parse_component();
+
+    assert_eq!(
+        component.options,
+        Some(SvelteOptions {
+            runes: Some(true),
+            immutable: Some(true),
+            accessors: Some(false),
+            namespace: Some(Namespace::Svg),
+            custom_element: Some(CustomElement {
+                tag: Some("my-tag".into()),
+                shadow: None,
+            }),
+            span: new_span(0, 91),
+            ..Default::default()
+        })
+    );
+    assert!(matches!(
+        &component.nodes[..],
+        [Node::SvelteOptionsElement(..)]
+    ));
+    assert!(error_reporter.is_empty())
+}
+
+#[test]
+fn svelte_options_custom_element_object() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let component = Parser::new(
+        r#"<svelte:options customElement={{ tag: "my-tag", shadow: "none", props: {} }} />"#,
+        &mut error_reporter,
+    )
+    .parse_component();
+
+    assert_eq!(
+        component.options.and_then(|options| options.custom_element),
+        Some(CustomElement {
+            tag: Some("my-tag".into()),
+            shadow: Some(ShadowMode::None),
+        })
+    );
+    assert!(error_reporter.is_empty())
+}
+
+#[test]
+fn svelte_options_errors() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let component = Parser::new(
+        r#"<svelte:options foo runes="yes" namespace={ns} customElement="MyTag" on:click {...rest} />"#,
+        &mut error_reporter,
+    )
+    .parse_component();
+
+    assert_eq!(component.options.map(|options| options.runes), Some(None));
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[
+            ParseError::new(ParseErrorKind::UnknownSvelteOption, new_span(16, 19)),
+            ParseError::new(ParseErrorKind::InvalidSvelteOptionValue, new_span(26, 31)),
+            ParseError::new(ParseErrorKind::InvalidSvelteOptionValue, new_span(42, 46)),
+            ParseError::new(ParseErrorKind::InvalidSvelteOptionValue, new_span(61, 68)),
+            ParseError::new(ParseErrorKind::UnknownSvelteOption, new_span(69, 77)),
+            ParseError::new(ParseErrorKind::UnknownSvelteOption, new_span(78, 87)),
+        ]
+    );
+
+    let mut error_reporter = CollectingErrorReporter::new();
+    let component = Parser::new(
+        "<svelte:options runes /><svelte:options />{#if a}<div><svelte:options /></div>{/if}",
+        &mut error_reporter,
+    )
+    .parse_component();
+
+    assert_eq!(
+        component.options.and_then(|options| options.runes),
+        Some(true)
+    );
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[
+            ParseError::new(ParseErrorKind::DuplicateSvelteMeta, new_span(24, 42)),
+            ParseError::new(ParseErrorKind::InvalidSvelteMetaPlacement, new_span(54, 72)),
+        ]
+    );
+}
+
+#[test]
+fn slots() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let nodes = Parser::new(
+        r#"<slot name="item" {item} index={i}>fallback</slot><slot />"#,
+        &mut error_reporter,
+    )
+    .parse();
+
+    let [Node::Slot(named), Node::Slot(default)] = &nodes[..] else {
+        panic!("expected two slots, got {nodes:?}");
+    };
+    assert_eq!(named.name(), Some("item"));
+    assert_eq!(named.props().count(), 2);
+    assert_eq!(named.children.len(), 1);
+    assert_eq!(named.span, new_span(0, 50));
+    assert_eq!(default.name(), None);
+    assert_eq!(default.props().count(), 0);
+    assert!(error_reporter.is_empty())
+}
+
+#[test]
+fn slot_attributes() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let nodes = Parser::new(
+        r#"<List let:item><div slot="header" let:title>{title}</div><svelte:fragment slot="footer" /></List>"#,
+        &mut error_reporter,
+    )
+    .parse();
+
+    let [Node::Element(list)] = &nodes[..] else {
+        panic!("expected a single element, got {nodes:?}");
+    };
+    assert_eq!(list.start_tag.let_directives().count(), 1);
+    let [Node::Element(header), Node::SvelteFragment(footer)] = &list.children[..] else {
+        panic!("expected a div and a fragment, got {:?}", list.children);
+    };
+    assert_eq!(header.start_tag.slot_name(), Some("header"));
+    assert_eq!(header.start_tag.let_directives().count(), 1);
+    assert_eq!(footer.start_tag.slot_name(), Some("footer"));
+    assert!(error_reporter.is_empty())
+}
+
+#[test]
+fn slot_errors() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    Parser::new(
+        r#"<slot name="default" on:click={f} /><div slot="a" /><Foo><p slot={a} /><p slot="b" /><p slot="b" /></Foo><p let:x />"#,
+        &mut error_reporter,
+    )
+    .parse();
+
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[
+            ParseError::new(ParseErrorKind::InvalidSlotName, new_span(6, 20)),
+            ParseError::new(
+                ParseErrorKind::InvalidSlotElementAttribute,
+                new_span(21, 33)
+            ),
+            ParseError::new(
+                ParseErrorKind::InvalidSlotAttributePlacement,
+                new_span(41, 49)
+            ),
+            ParseError::new(ParseErrorKind::DynamicSlotAttribute, new_span(60, 68)),
+            ParseError::new(ParseErrorKind::DuplicateSlotAttribute, new_span(88, 96)),
+            ParseError::new(
+                ParseErrorKind::InvalidLetDirectivePlacement,
+                new_span(108, 113)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn auto_closed_elements() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let nodes = Parser::new(
+        "<ul><li>a<li>b</ul><p>text<div>block</div><br><img src=x>",
+        &mut error_reporter,
+    )
+    .parse();
+
+    let [Node::Element(list), Node::Element(paragraph), Node::Element(div), Node::Element(br), Node::Element(img)] =
+        &nodes[..]
+    else {
+        panic!("expected five elements, got {nodes:?}");
+    };
+    let [Node::Element(first), Node::Element(second)] = &list.children[..] else {
+        panic!("expected two list items, got {:?}", list.children);
+    };
+    assert_eq!(first.span, new_span(4, 9));
+    assert!(first.end_tag.is_none());
+    assert_eq!(second.span, new_span(9, 14));
+    assert_eq!(paragraph.span, new_span(19, 26));
+    assert_eq!(paragraph.children.len(), 1);
+    assert_eq!(div.children.len(), 1);
+    assert!(br.children.is_empty() && br.end_tag.is_none());
+    assert!(img.children.is_empty() && img.end_tag.is_none());
+    assert!(error_reporter.is_empty())
+}
+
+#[test]
+fn auto_closed_element_errors() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let nodes = Parser::new("<p><div></div></p><br></br>", &mut error_reporter).parse();
+
+    assert!(matches!(
+        &nodes[..],
+        [
+            Node::Element(..),
+            Node::Element(..),
+            Node::EndTag(..),
+            Node::Element(..),
+            Node::EndTag(..)
+        ]
+    ));
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[
+            ParseError::new(ParseErrorKind::AutoClosedElementEndTag, new_span(14, 18)),
+            ParseError::new(ParseErrorKind::InvalidVoidElementContent, new_span(22, 27))
+                .with_fix(Fix::delete("Remove `</br>`", new_span(22, 27))),
+        ]
+    );
+}
+
+#[test]
+fn escapable_raw_text() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let nodes = Parser::new(
+        "<textarea><b>{value}</b></textarea><title>a < b</title>",
+        &mut error_reporter,
+    )
+    .parse();
+
+    let [Node::Element(textarea), Node::Element(title)] = &nodes[..] else {
+        panic!("expected two elements, got {nodes:?}");
+    };
+    let [Node::Text(before), Node::Mustache(..), Node::Text(after)] = &textarea.children[..] else {
+        panic!(
+            "expected text around a mustache, got {:?}",
+            textarea.children
+        );
+    };
+    assert_eq!(before.text, "<b>");
+    assert_eq!(before.span, new_span(10, 13));
+    assert_eq!(after.text, "</b>");
+    assert_eq!(after.span, new_span(20, 24));
+    assert!(textarea.end_tag.is_some());
+    assert_eq!(
+        title.children,
+        vec![Text {
+            text: "a < b".into(),
+            data: "a < b".into(),
+            span: new_span(42, 47),
+        }
+        .into()]
+    );
+    assert!(error_reporter.is_empty())
+}
+
+#[test]
+fn raw_text() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let nodes = Parser::new(
+        "<svelte:head><script>if (a < b) { c() }</script><style></style></svelte:head>",
+        &mut error_reporter,
+    )
+    .parse();
+
+    let [Node::SvelteHead(head)] = &nodes[..] else {
+        panic!("expected svelte:head, got {nodes:?}");
+    };
+    let [Node::Element(script), Node::Element(style)] = &head.children[..] else {
+        panic!("expected a script and a style, got {:?}", head.children);
+    };
+    assert_eq!(
+        script.children,
+        vec![Text {
+            text: "if (a < b) { c() }".into(),
+            data: "if (a < b) { c() }".into(),
+            span: new_span(21, 39),
+        }
+        .into()]
+    );
+    assert_eq!(script.span, new_span(13, 48));
+    assert!(style.children.is_empty());
+    assert!(style.end_tag.is_some());
+    assert!(error_reporter.is_empty())
+}
+
+#[test]
+fn character_references() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let nodes = Parser::new(
+        r#"<a title="&lt;&copy=&notit;&#39;">a&amp;b&nbsp;&#x27;&#128;&copy2024 &unknown; &#0; &</a>"#,
+        &mut error_reporter,
+    )
+    .parse();
+
+    let [Node::Element(element)] = &nodes[..] else {
+        panic!("expected an element, got {nodes:?}");
+    };
+    let [Node::Text(text)] = &element.children[..] else {
+        panic!("expected text, got {:?}", element.children);
+    };
+    assert_eq!(
+        text.text,
+        "a&amp;b&nbsp;&#x27;&#128;&copy2024 &unknown; &#0; &"
+    );
+    assert_eq!(text.data, "a&b\u{a0}'€©2024 &unknown; \u{fffd} &");
+
+    let AttributeItem::HtmlAttribute(title) = &element.start_tag.attributes[0].attribute_item
+    else {
+        panic!("expected an html attribute");
+    };
+    let value = &title
+        .attribute_initializer
+        .as_ref()
+        .unwrap()
+        .attribute_value;
+    assert_eq!(value.static_text(), Some("<&copy=&notit;'"));
+    assert!(error_reporter.is_empty())
+}
+
+#[test]
+fn snippet_block() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let nodes = Parser::new(
+        "{#snippet row(item, { id }, index = 0)}<td>{item}</td>{/snippet}{@render row?.(a, b, 1)}",
+        &mut error_reporter,
+    )
+    .parse();
+
+    let [Node::SnippetBlock(snippet), Node::Mustache(render)] = &nodes[..] else {
+        panic!("expected a snippet and a render tag, got {nodes:?}");
+    };
+    let MustacheItem::BlockOpen(BlockOpen::SnippetBlockOpen(open)) = &snippet.open.mustache_item
+    else {
+        panic!("expected a snippet open, got {:?}", snippet.open);
+    };
+    assert_eq!(&*open.name.sym, "row");
+    assert_eq!(open.name.span, new_span(10, 13));
+    assert!(matches!(
+        &open.params[..],
+        [Pat::Ident(..), Pat::Object(..), Pat::Assign(..)]
+    ));
+    assert_eq!(open.params[1].span(), new_span(20, 26));
+    assert_eq!(open.span, new_span(1, 38));
+    assert_eq!(snippet.children.len(), 1);
+    assert!(matches!(
+        snippet.close.as_ref().map(|close| &close.mustache_item),
+        Some(MustacheItem::BlockClose(BlockClose::SnippetClose(..)))
+    ));
+    assert_eq!(snippet.span, new_span(0, 64));
+    assert!(matches!(render.mustache_item, MustacheItem::RenderTag(..)));
+    assert!(error_reporter.is_empty())
+}
+
+#[test]
+fn snippet_errors() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    Parser::new(
+        "{#snippet (a)}{/snippet}{#snippet name}{/snippet}{#snippet name(a = )}{/snippet}\
+         {@render name}{#snippet panic(a = <!--\n/b/)}{/snippet}",
+        &mut error_reporter,
+    )
+    .parse();
+
+    // Invalid snippets are still opened, so their closes aren't unexpected
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[
+            ParseError::new(ParseErrorKind::ExpectedSnippetName, new_span(1, 13)),
+            ParseError::new(ParseErrorKind::ExpectedSnippetParameters, new_span(25, 38)),
+            ParseError::new(
+                ParseErrorKind::InvalidJavaScript(SyntaxError::TS1109.into()),
+                new_span(68, 69)
+            ),
+            ParseError::new(ParseErrorKind::InvalidRenderExpression, new_span(89, 93)),
+            ParseError::new(ParseErrorKind::JavaScriptParserFailed, new_span(109, 123)),
+        ]
+    );
+}
+
+#[test]
+fn snippet_parameter_error_spans() {
+    // The parameters are parsed with ` => {}` added on, but errors stay within the parameters
+    let mut error_reporter = CollectingErrorReporter::new();
+    Parser::new("{#snippet s(a, b = `x)}{/snippet}", &mut error_reporter).parse();
+
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[ParseError::new(
+            ParseErrorKind::InvalidJavaScript(SyntaxError::Eof.into()),
+            new_span(22, 22)
+        )]
+    );
+}
+
+#[test]
+fn const_tag_placement() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    Parser::new(
+        "{#if a}{@const b = 1}{:else}{@const c = 2}{/if}\
+         {#each items as item}{@const d = item}{/each}\
+         {#await p}{@const e = 1}{:then value}{@const f = value}{/await}\
+         {#key k}{@const g = 1}{/key}\
+         <Foo>{@const h = 1}</Foo><svelte:fragment>{@const i = 1}</svelte:fragment>\
+         <div>{@const j = 1}</div>{#if a}<div>{@const k = 1}</div>{/if}",
+        &mut error_reporter,
+    )
+    .parse();
+
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[
+            ParseError::new(ParseErrorKind::InvalidConstTagPlacement, new_span(103, 115)),
+            ParseError::new(ParseErrorKind::InvalidConstTagPlacement, new_span(164, 176)),
+            ParseError::new(ParseErrorKind::InvalidConstTagPlacement, new_span(263, 275)),
+            ParseError::new(ParseErrorKind::InvalidConstTagPlacement, new_span(295, 307)),
+        ]
+    );
+}
+
+#[test]
+fn tag_placement_in_attributes_and_raw_text() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let nodes = Parser::new(
+        r#"<div title="{@html a}" class={@debug }></div><textarea>{@html b}{@debug c}</textarea><p title={@html a({ b })}></p>"#,
+        &mut error_reporter,
+    )
+    .parse();
+
+    // The nested braces don't end the misplaced tag early
+    assert_eq!(nodes.len(), 3);
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[
+            ParseError::new(ParseErrorKind::InvalidHtmlTagPlacement, new_span(13, 20)),
+            ParseError::new(ParseErrorKind::InvalidDebugTagPlacement, new_span(30, 36)),
+            ParseError::new(ParseErrorKind::InvalidHtmlTagPlacement, new_span(56, 63)),
+            ParseError::new(ParseErrorKind::InvalidDebugTagPlacement, new_span(65, 73)),
+            ParseError::new(ParseErrorKind::InvalidHtmlTagPlacement, new_span(95, 109)),
+        ]
+    );
+}
+
+#[test]
+fn invalid_javascript_recovery() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let nodes = Parser::new("{a +} b <div {c +}></div>{d}", &mut error_reporter).parse();
+
+    assert_eq!(nodes.len(), 4);
+    assert_eq!(
+        nodes[0],
+        Mustache {
+            mustache_open: MustacheOpenToken {
+                span: new_span(0, 1),
+            },
+            leading_whitespace: None,
+            mustache_item: InvalidSyntax {
+                text: "a +".into(),
+                span: new_span(1, 4),
+            }
+            .into(),
+            trailing_whitespace: None,
+            mustache_close: Some(MustacheCloseToken {
+                span: new_span(4, 5),
+            }),
+            span: new_span(0, 5),
+        }
+        .into()
+    );
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[
+            ParseError::new(
+                ParseErrorKind::InvalidJavaScript(SyntaxError::TS1109.into()),
+                new_span(4, 5)
+            ),
+            ParseError::new(
+                ParseErrorKind::InvalidJavaScript(SyntaxError::TS1109.into()),
+                new_span(17, 18)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn each_block_recovery() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    let nodes = Parser::new(
+        "{#each items}{/each}{#each items as }{/each}{#each items as item (item.id}{/each}{#if}{/if}",
+        &mut error_reporter,
+    )
+    .parse();
+
+    assert_eq!(nodes.len(), 4);
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[
+            ParseError::new(ParseErrorKind::ExpectedEachAs, new_span(12, 12)),
+            ParseError::new(
+                ParseErrorKind::InvalidJavaScript(
+                    SyntaxError::Unexpected {
+                        got: "}".into(),
+                        expected: "yield, an identifier, [ or {"
+                    }
+                    .into()
+                ),
+                new_span(36, 37)
+            ),
+            ParseError::new(ParseErrorKind::EachKeyNotClosed, new_span(72, 73))
+                .with_fix(Fix::insert("Insert missing `)`", 73, ")")),
+            ParseError::new(
+                ParseErrorKind::MissingWhitespaceAfterBlockOpen,
+                new_span(84, 85)
+            )
+            .with_fix(Fix::insert("Insert a space", 85, " ")),
+            ParseError::new(
+                ParseErrorKind::InvalidJavaScript(SyntaxError::TS1109.into()),
+                new_span(85, 86)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn javascript_errors() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    Parser::new(
+        "<script>let a = ;</script>{1 = 2}{#each items as {a b}}{/each}",
+        &mut error_reporter,
+    )
+    .parse();
+
+    let errors = error_reporter.parse_errors();
+    assert_eq!(
+        errors,
+        &[
+            ParseError::new(
+                ParseErrorKind::InvalidJavaScript(SyntaxError::TS1109.into()),
+                new_span(16, 17)
+            ),
+            ParseError::new(
+                ParseErrorKind::InvalidJavaScript(SyntaxError::TS2406.into()),
+                new_span(27, 28)
+            ),
+            ParseError::new(
+                ParseErrorKind::InvalidJavaScript(
+                    SyntaxError::Expected(&swc_ecma_parser::token::Token::Comma, "b".into()).into()
+                ),
+                new_span(52, 53)
+            ),
+        ]
+    );
+    let ParseErrorKind::InvalidJavaScript(error) = &errors[0].kind else {
+        unreachable!()
+    };
+    assert_eq!(error.message, "Expression expected");
+}
+
+#[test]
+fn javascript_parser_panic() {
+    // swc fails a debug assertion on a legacy comment followed by a regular expression
+    let mut error_reporter = CollectingErrorReporter::new();
+    let nodes = Parser::new("{<!--\n/if}<script><!--\n/a/</script>", &mut error_reporter).parse();
+
+    assert_eq!(nodes.len(), 2);
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[
+            ParseError::new(ParseErrorKind::JavaScriptParserFailed, new_span(1, 9)),
+            ParseError::new(ParseErrorKind::JavaScriptParserFailed, new_span(18, 26)),
+        ]
+    );
+}
+
+#[test]
+fn warnings() {
+    let mut error_reporter = CollectingErrorReporter::new();
+    Parser::new(
+        "<script context=\"module\"></script>{#if a} {:else}b{/if}{#each items as item}{/each}",
+        &mut error_reporter,
+    )
+    .parse();
+
+    assert!(error_reporter.is_empty());
+    assert_eq!(
+        error_reporter.warnings().cloned().collect::<Vec<_>>(),
+        vec![
+            Diagnostic::warning(ParseErrorKind::DeprecatedScriptContext, new_span(8, 24))
+                .with_tag(DiagnosticTag::Deprecated),
+            Diagnostic::warning(ParseErrorKind::EmptyBlock, new_span(41, 42))
+                .with_related(new_span(34, 41), "block opened here")
+                .with_tag(DiagnosticTag::Unnecessary),
+        ]
+    );
+    assert_eq!(error_reporter.diagnostics().len(), 2);
+    assert_eq!(error_reporter.hints().count(), 0);
+}
+
+#[test]
+fn quick_fixes() {
+    let source = "{#if a}{@const(b) = 1}{b{/fi}";
+    let mut error_reporter = CollectingErrorReporter::new();
+    Parser::new(source, &mut error_reporter).parse();
+
+    assert_eq!(
+        error_reporter.parse_errors(),
+        &[
+            ParseError::new(
+                ParseErrorKind::MissingWhitespaceAfterConstTag,
+                new_span(13, 14)
+            )
+            .with_fix(Fix::insert("Insert a space", 14, " ")),
+            ParseError::new(ParseErrorKind::MustacheNotClosed, new_span(23, 24))
+                .with_fix(Fix::insert("Insert missing `}`", 24, "}")),
+            ParseError::new(ParseErrorKind::UnknownBlockClose, new_span(26, 28)).with_fix(
+                Fix::replace("Replace `{/fi}` with `{/if}`", new_span(26, 28), "if")
+            ),
+            ParseError::new(ParseErrorKind::BlockNotClosed, new_span(1, 6)),
+        ]
+    );
+
+    let fixed = apply_fixes(source, error_reporter.parse_errors());
+    assert_eq!(fixed, "{#if a}{@const (b) = 1}{b}{/if}");
+    let mut error_reporter = CollectingErrorReporter::new();
+    Parser::new(&fixed, &mut error_reporter).parse();
+    assert!(error_reporter.is_empty());
+
+    // Only the first fix of each error is applied, and ones overlapping an earlier fix are skipped
+    let errors =
+        [
+            ParseError::new(ParseErrorKind::UnknownBlockClose, new_span(2, 4))
+                .with_fix(Fix::replace("", new_span(2, 4), "if"))
+                .with_fix(Fix::replace("", new_span(2, 4), "each")),
+            ParseError::new(ParseErrorKind::UnknownBlockClose, new_span(3, 5))
+                .with_fix(Fix::replace("", new_span(3, 5), "key")),
+            ParseError::new(ParseErrorKind::MustacheNotClosed, new_span(3, 4))
+                .with_fix(Fix::insert("", 4, "}")),
+        ];
+    assert_eq!(apply_fixes("{/fi", &errors), "{/if}");
+}
+
+#[test]
+fn svelte_ignore() {
+    let empty_blocks = |source: &str| {
+        let mut error_reporter = CollectingErrorReporter::new();
+        Parser::new(source, &mut error_reporter).parse();
+        error_reporter
+            .warnings()
+            .filter(|warning| warning.kind == ParseErrorKind::EmptyBlock)
+            .count()
+    };
+
+    assert_eq!(empty_blocks("{#if a} {/if}"), 1);
+    assert_eq!(
+        empty_blocks("<!-- svelte-ignore block_empty -->{#if a} {/if}"),
+        0
+    );
+    // Text and other comments in between don't matter, and the whole subtree is ignored
+    assert_eq!(
+        empty_blocks(
+            "<!-- svelte-ignore block_empty -->\n<!-- hi -->\n<div>{#if a} {/if}<p>{#each a as b} {/each}</p></div>"
+        ),
+        0
+    );
+    assert_eq!(
+        empty_blocks("{#if a}<!-- svelte-ignore block_empty -->{#key a} {/key}{:else} {/if}"),
+        1
+    );
+    assert_eq!(
+        empty_blocks("<!-- svelte-ignore block_empty --><p></p>{#if a} {/if}"),
+        1
+    );
+    assert_eq!(
+        empty_blocks("<!-- svelte-ignore a11y_autofocus -->{#if a} {/if}"),
+        1
+    );
+    assert_eq!(empty_blocks("<!-- svelte-ignore -->{#if a} {/if}"), 1);
+    // Old codes and codes without commas between them only work outside of runes mode
+    assert_eq!(
+        empty_blocks("<!-- svelte-ignore empty-block -->{#if a} {/if}"),
+        0
+    );
+    assert_eq!(
+        empty_blocks("<!-- svelte-ignore a11y_autofocus block_empty -->{#if a} {/if}"),
+        0
+    );
+    assert_eq!(
+        empty_blocks(
+            "<svelte:options runes />\n<!-- svelte-ignore a11y_autofocus block_empty -->{#if a} {/if}"
+        ),
+        1
+    );
+    assert_eq!(
+        empty_blocks(
+            "<svelte:options runes />\n<!-- svelte-ignore a11y_autofocus, block_empty (no content yet) -->{#if a} {/if}"
+        ),
+        0
+    );
+
+    assert_eq!(
+        svelte_ignore_codes(" svelte-ignore a11y-autofocus empty-block ", false),
+        [
+            "a11y-autofocus",
+            "a11y_autofocus",
+            "empty-block",
+            "block_empty"
+        ]
+    );
+    assert_eq!(
+        svelte_ignore_codes(" svelte-ignore a, b, a c ", true),
+        ["a", "b"]
+    );
+    assert!(svelte_ignore_codes(" svelte-ignored a ", false).is_empty());
+}
@@ -0,0 +1,185 @@
+use pretty_assertions::assert_eq;
+use svelters::{
+    error::{CollectingErrorReporter, ParseError, ParseErrorKind},
+    parser::{new_span, Parser},
+    render::Renderer,
+};
+
+#[test]
+fn render_errors() {
+    let source = "<p>{a";
+    let mut error_reporter = CollectingErrorReporter::new();
+    Parser::new(source, &mut error_reporter).parse();
+
+    assert_eq!(
+        Renderer::new(source)
+            .with_file_name("App.svelte")
+            .render(&error_reporter),
+        "\
+error[expected_token]: Expected token }
+ --> App.svelte:1:5
+  |
+1 | <p>{a
+  |     ^ expected `}`
+  |
+  = help: add a `}` to close the mustache
+
+error[element_unclosed]: Element was left open
+ --> App.svelte:1:1
+  |
+1 | <p>{a
+  | ^^^ opened here
+"
+    );
+}
+
+#[test]
+fn render_multiline_span() {
+    let source =
+        "first\n\tsecond {a +}\nthird\nfourth\nfifth\nsixth\nseventh\neighth\nninth\ntenth";
+    let renderer = Renderer::new(source);
+
+    assert_eq!(
+        renderer.render_error(&ParseError::new(
+            ParseErrorKind::BlockNotClosed,
+            new_span(8, 71)
+        )),
+        "\
+error[block_unclosed]: Block was left open
+  --> 2:3
+   |
+ 2 | \tsecond {a +}
+   | \t ^^^^^^^^^^^
+ 3 | third
+   | ^^^^^
+...
+10 | tenth
+   | ^^^^^ opened here
+   |
+   = help: add a matching close block, like `{/if}`
+"
+    );
+}
+
+#[test]
+fn render_colors() {
+    let source = "{@const a}";
+    let renderer = Renderer::new(source).with_colors(true);
+
+    assert_eq!(
+        renderer.render_error(&ParseError::new(
+            ParseErrorKind::TagNotClosed,
+            new_span(10, 10)
+        )),
+        "\x1b[1;31merror[expected_token]\x1b[0m: \x1b[1mExpected token >\x1b[0m
+ \x1b[1;34m-->\x1b[0m 1:11
+  \x1b[1;34m|\x1b[0m
+\x1b[1;34m1 |\x1b[0m {@const a}
+  \x1b[1;34m|\x1b[0m           \x1b[1;31m^ expected `>`\x1b[0m
+"
+    );
+}
+
+#[test]
+fn error_codes() {
+    let source = "{#if a}<textarea>{@html b}</textarea>{a +}";
+    let mut error_reporter = CollectingErrorReporter::new();
+    Parser::new(source, &mut error_reporter).parse();
+
+    let errors: Vec<_> = error_reporter
+        .parse_errors()
+        .iter()
+        .map(|error| (error.kind.code(), error.kind.to_string()))
+        .collect();
+    assert_eq!(
+        errors,
+        vec![
+            (
+                "tag_invalid_placement",
+                "{@html ...} tag cannot be in an attribute value, `<textarea>` or `<title>`".into()
+            ),
+            ("js_parse_error", "Expression expected".into()),
+            ("block_unclosed", "Block was left open".into()),
+        ]
+    );
+    assert_eq!(
+        error_reporter.parse_errors()[0].kind.template(),
+        "{@%name% ...} tag cannot be in an attribute value, `<textarea>` or `<title>`"
+    );
+    assert_eq!(
+        error_reporter.parse_errors()[0].kind.params(),
+        vec![("name", "html".to_string())]
+    );
+}
+
+#[test]
+fn error_codes_match_svelte() {
+    let source = "{#if a}<textarea>{@debug b}</textarea>{/foo}{/if}{#snippet (x)}{/snippet}\
+                  <script></script><script></script>";
+    let mut error_reporter = CollectingErrorReporter::new();
+    Parser::new(source, &mut error_reporter).parse();
+
+    let codes: Vec<_> = error_reporter
+        .parse_errors()
+        .iter()
+        .map(|error| (&error.kind, error.kind.code()))
+        .collect();
+    assert_eq!(
+        codes,
+        vec![
+            (
+                &ParseErrorKind::InvalidDebugTagPlacement,
+                "tag_invalid_placement"
+            ),
+            (&ParseErrorKind::UnknownBlockClose, "block_unexpected_close"),
+            (&ParseErrorKind::ExpectedSnippetName, "expected_identifier"),
+            (&ParseErrorKind::DuplicateInstanceScript, "script_duplicate"),
+        ]
+    );
+}
+
+#[test]
+fn error_messages_name_the_expected_token() {
+    let source = r#"<style>a[href {} p { color: red </style><p class="a></p>"#;
+    let mut error_reporter = CollectingErrorReporter::new();
+    Parser::new(source, &mut error_reporter).parse();
+
+    let messages: Vec<_> = error_reporter
+        .parse_errors()
+        .iter()
+        .map(|error| (error.kind.code(), error.kind.to_string()))
+        .take(4)
+        .collect();
+    assert_eq!(
+        messages,
+        [
+            ("expected_token", "Expected token ]".into()),
+            ("expected_token", "Expected token ;".into()),
+            ("expected_token", "Expected token }".into()),
+            ("expected_token", "Expected token \"".into()),
+        ]
+    );
+}
+
+#[test]
+fn render_warning_with_related_span() {
+    let source = "{#if a}b\n{:else} {/if}";
+    let mut error_reporter = CollectingErrorReporter::new();
+    Parser::new(source, &mut error_reporter).parse();
+
+    assert_eq!(
+        Renderer::new(source).render(&error_reporter),
+        "\
+warning[block_empty]: Empty block
+ --> 2:8
+  |
+2 | {:else} {/if}
+  |        ^
+  |
+ ::: 1:1
+  |
+1 | {#if a}b
+  | ------- block opened here
+"
+    );
+}